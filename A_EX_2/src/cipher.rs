@@ -0,0 +1,299 @@
+//! Kryptoanaliza szyfrów Cezara i Vigenère'a na podstawie histogramu liter.
+//!
+//! Szyfry działają wyłącznie na literach ASCII: wielkość liter jest zachowywana,
+//! a pozostałe znaki przechodzą bez zmian i nie przesuwają pozycji klucza.
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::language::LanguageProfile;
+use crate::{build_histogram, normalize_letters};
+
+/// Rodzaj szyfru łamanego przez program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CipherKind {
+    Caesar,
+    Vigenere,
+}
+
+impl FromStr for CipherKind {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "caesar" | "cezar" => Ok(CipherKind::Caesar),
+            "vigenere" => Ok(CipherKind::Vigenere),
+            _ => Err(format!("Nieznany szyfr: {}", raw.trim())),
+        }
+    }
+}
+
+/// Najlepsze przesunięcie znalezione dla szyfru Cezara.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaesarGuess {
+    /// Przesunięcie użyte przy szyfrowaniu (`0..26`).
+    pub shift: u8,
+    /// Wartość statystyki chi-kwadrat dla odszyfrowanego tekstu.
+    pub score: f64,
+    pub plaintext: String,
+}
+
+/// Kandydat na długość klucza Vigenère'a.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyLengthCandidate {
+    pub length: usize,
+    /// Średni indeks koincydencji kolumn tekstu podzielonego co `length` liter.
+    pub coincidence: f64,
+    /// Liczba odległości między powtórzonymi trigramami, które dzielą się przez `length`.
+    pub kasiski_votes: usize,
+}
+
+/// Wynik łamania szyfru Vigenère'a.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VigenereGuess {
+    pub key: String,
+    pub plaintext: String,
+    /// Wszystkie sprawdzone długości klucza, od najkrótszej.
+    pub candidates: Vec<KeyLengthCandidate>,
+}
+
+/// Przesuwa każdą literę ASCII o `shift` pozycji w przód, zachowując wielkość liter.
+pub fn shift_letters(text: &str, shift: u8) -> String {
+    text.chars().map(|ch| shift_char(ch, shift)).collect()
+}
+
+fn shift_char(ch: char, shift: u8) -> char {
+    let base = match ch {
+        'a'..='z' => b'a',
+        'A'..='Z' => b'A',
+        _ => return ch,
+    };
+    (base + (ch as u8 - base + shift % 26) % 26) as char
+}
+
+/// Szyfruje tekst szyfrem Vigenère'a. Klucz może zawierać tylko litery ASCII.
+pub fn vigenere_encrypt(text: &str, key: &str) -> Result<String, String> {
+    apply_vigenere(text, key, false)
+}
+
+/// Odszyfrowuje tekst zaszyfrowany szyfrem Vigenère'a.
+pub fn vigenere_decrypt(text: &str, key: &str) -> Result<String, String> {
+    apply_vigenere(text, key, true)
+}
+
+fn apply_vigenere(text: &str, key: &str, decrypt: bool) -> Result<String, String> {
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(format!("Niepoprawny klucz: {key}"));
+    }
+
+    let shifts: Vec<u8> = key
+        .bytes()
+        .map(|b| {
+            let shift = b.to_ascii_lowercase() - b'a';
+            if decrypt {
+                (26 - shift) % 26
+            } else {
+                shift
+            }
+        })
+        .collect();
+
+    let mut position = 0;
+    Ok(text
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphabetic() {
+                let shifted = shift_char(ch, shifts[position % shifts.len()]);
+                position += 1;
+                shifted
+            } else {
+                ch
+            }
+        })
+        .collect())
+}
+
+/// Oczekiwany rozkład liter `a`–`z` wyznaczony z profilu języka (suma równa 1).
+/// Litery spoza ASCII są pomijane, a brakujące dostają małą, niezerową wartość.
+pub fn expected_distribution(profile: &LanguageProfile) -> [f64; 26] {
+    let mut distribution = [0.0001; 26];
+    for &(letter, frequency) in profile.frequencies {
+        if letter.is_ascii_lowercase() {
+            distribution[(letter as u8 - b'a') as usize] = frequency.max(0.01);
+        }
+    }
+    let total: f64 = distribution.iter().sum();
+    distribution.map(|f| f / total)
+}
+
+/// Statystyka chi-kwadrat histogramu względem oczekiwanego rozkładu liter `a`–`z`.
+/// Im mniejsza wartość, tym tekst bardziej przypomina język profilu.
+pub fn chi_squared(counts: &BTreeMap<char, usize>, expected: &[f64; 26]) -> f64 {
+    let total: usize = counts
+        .iter()
+        .filter(|(letter, _)| letter.is_ascii_lowercase())
+        .map(|(_, count)| count)
+        .sum();
+    if total == 0 {
+        return f64::INFINITY;
+    }
+
+    expected
+        .iter()
+        .enumerate()
+        .map(|(index, probability)| {
+            let letter = (b'a' + index as u8) as char;
+            let observed = counts.get(&letter).copied().unwrap_or(0) as f64;
+            let expected = probability * total as f64;
+            (observed - expected).powi(2) / expected
+        })
+        .sum()
+}
+
+/// Indeks koincydencji: prawdopodobieństwo, że dwie losowo wybrane litery są takie same.
+pub fn index_of_coincidence(counts: &BTreeMap<char, usize>) -> f64 {
+    let total: usize = counts.values().sum();
+    if total < 2 {
+        return 0.0;
+    }
+    let pairs: usize = counts.values().map(|&n| n * n.saturating_sub(1)).sum();
+    pairs as f64 / (total * (total - 1)) as f64
+}
+
+/// Znajduje przesunięcie Cezara, dla którego odszyfrowany tekst ma najmniejsze chi-kwadrat.
+pub fn crack_caesar(ciphertext: &str, profile: &LanguageProfile) -> Result<CaesarGuess, String> {
+    let letters = normalize_letters(ciphertext)?;
+    let (shift, score) = best_shift(&letters, &expected_distribution(profile));
+    Ok(CaesarGuess {
+        shift,
+        score,
+        plaintext: shift_letters(ciphertext, (26 - shift) % 26),
+    })
+}
+
+/// Sprawdza wszystkie przesunięcia dla ciągu małych liter i zwraca `(przesunięcie, chi-kwadrat)`.
+fn best_shift(letters: &[char], expected: &[f64; 26]) -> (u8, f64) {
+    let mut best = (0, f64::INFINITY);
+    for shift in 0..26u8 {
+        let candidate: Vec<char> = letters
+            .iter()
+            .map(|&ch| shift_char(ch, (26 - shift) % 26))
+            .collect();
+        let score = chi_squared(&build_histogram(&candidate), expected);
+        if score < best.1 {
+            best = (shift, score);
+        }
+    }
+    best
+}
+
+/// Dzieli ciąg liter na `length` kolumn (co `length`-ta litera).
+fn columns(letters: &[char], length: usize) -> Vec<Vec<char>> {
+    let mut columns = vec![Vec::new(); length];
+    for (index, &letter) in letters.iter().enumerate() {
+        columns[index % length].push(letter);
+    }
+    columns
+}
+
+/// Test Kasiskiego: zlicza, ile odległości między powtórzonymi trigramami dzieli się przez
+/// każdą długość klucza z zakresu `2..=max_length`.
+pub fn kasiski_votes(letters: &[char], max_length: usize) -> BTreeMap<usize, usize> {
+    let mut last_seen: BTreeMap<&[char], usize> = BTreeMap::new();
+    let mut votes = BTreeMap::new();
+
+    for (position, trigram) in letters.windows(3).enumerate() {
+        if let Some(previous) = last_seen.insert(trigram, position) {
+            let distance = position - previous;
+            for length in 2..=max_length {
+                if distance % length == 0 {
+                    *votes.entry(length).or_insert(0) += 1;
+                }
+            }
+        }
+    }
+    votes
+}
+
+/// Ocenia długości klucza `1..=max_length` indeksem koincydencji i testem Kasiskiego.
+pub fn estimate_key_lengths(letters: &[char], max_length: usize) -> Vec<KeyLengthCandidate> {
+    let votes = kasiski_votes(letters, max_length);
+    (1..=max_length.min(letters.len()))
+        .map(|length| {
+            let columns = columns(letters, length);
+            let coincidence = columns
+                .iter()
+                .map(|column| index_of_coincidence(&build_histogram(column)))
+                .sum::<f64>()
+                / length as f64;
+            KeyLengthCandidate {
+                length,
+                coincidence,
+                kasiski_votes: votes.get(&length).copied().unwrap_or(0),
+            }
+        })
+        .collect()
+}
+
+/// Wybiera długość klucza spośród kandydatów, których indeks koincydencji jest bliski najlepszemu
+/// (wielokrotności prawdziwej długości mają podobny indeks): najwięcej głosów Kasiskiego,
+/// a przy remisie najkrótsza długość.
+pub fn pick_key_length(candidates: &[KeyLengthCandidate]) -> Option<usize> {
+    let best = candidates.iter().map(|c| c.coincidence).fold(0.0, f64::max);
+    candidates
+        .iter()
+        .filter(|c| c.coincidence >= best * 0.9)
+        .max_by(|a, b| {
+            a.kasiski_votes
+                .cmp(&b.kasiski_votes)
+                .then(b.length.cmp(&a.length))
+        })
+        .map(|c| c.length)
+}
+
+/// Łamie szyfr Vigenère'a: szacuje długość klucza, a potem łamie każdą kolumnę jak szyfr Cezara.
+pub fn crack_vigenere(
+    ciphertext: &str,
+    max_key_length: usize,
+    profile: &LanguageProfile,
+) -> Result<VigenereGuess, String> {
+    let letters = normalize_letters(ciphertext)?;
+    let candidates = estimate_key_lengths(&letters, max_key_length.max(1));
+    let length = pick_key_length(&candidates).ok_or("Za mało liter do analizy")?;
+
+    let expected = expected_distribution(profile);
+    let key: String = columns(&letters, length)
+        .iter()
+        .map(|column| (b'a' + best_shift(column, &expected).0) as char)
+        .collect();
+    let plaintext = vigenere_decrypt(ciphertext, &key)?;
+
+    Ok(VigenereGuess {
+        key,
+        plaintext,
+        candidates,
+    })
+}
+
+/// Formatuje wynik łamania szyfru Cezara.
+pub fn format_caesar(guess: &CaesarGuess) -> Vec<String> {
+    vec![
+        format!("Przesunięcie: {}", guess.shift),
+        format!("Chi-kwadrat: {:.2}", guess.score),
+        format!("Tekst: {}", guess.plaintext),
+    ]
+}
+
+/// Formatuje wynik łamania szyfru Vigenère'a wraz z ocenami długości klucza.
+pub fn format_vigenere(guess: &VigenereGuess) -> Vec<String> {
+    let mut lines = vec![
+        format!("Klucz: {}", guess.key),
+        format!("Tekst: {}", guess.plaintext),
+    ];
+    for candidate in &guess.candidates {
+        lines.push(format!(
+            "długość {}: IC {:.4}, Kasiski {}",
+            candidate.length, candidate.coincidence, candidate.kasiski_votes
+        ));
+    }
+    lines
+}
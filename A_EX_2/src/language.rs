@@ -0,0 +1,218 @@
+//! Referencyjne profile częstości liter i zgadywanie języka na podstawie histogramu.
+use std::collections::BTreeMap;
+
+/// Profil języka: względne częstości liter (w procentach) dla typowego tekstu.
+#[derive(Debug, Clone, Copy)]
+pub struct LanguageProfile {
+    /// Kod języka (ISO 639-1), np. `pl`.
+    pub code: &'static str,
+    /// Nazwa wyświetlana w raporcie.
+    pub name: &'static str,
+    /// Częstości małych liter w procentach.
+    pub frequencies: &'static [(char, f64)],
+}
+
+/// Wbudowane profile języków porównywane przez `guess_language`.
+pub const PROFILES: &[LanguageProfile] = &[
+    LanguageProfile {
+        code: "pl",
+        name: "polski",
+        frequencies: &[
+            ('a', 10.503),
+            ('b', 1.740),
+            ('c', 3.895),
+            ('d', 3.725),
+            ('e', 7.352),
+            ('f', 0.143),
+            ('g', 1.731),
+            ('h', 1.015),
+            ('i', 8.328),
+            ('j', 1.836),
+            ('k', 2.753),
+            ('l', 2.564),
+            ('m', 2.515),
+            ('n', 6.237),
+            ('o', 6.667),
+            ('p', 2.445),
+            ('r', 5.243),
+            ('s', 5.224),
+            ('t', 2.475),
+            ('u', 2.062),
+            ('v', 0.012),
+            ('w', 5.813),
+            ('x', 0.004),
+            ('y', 3.206),
+            ('z', 4.852),
+            ('ą', 0.699),
+            ('ć', 0.743),
+            ('ę', 1.035),
+            ('ł', 2.109),
+            ('ń', 0.362),
+            ('ó', 1.141),
+            ('ś', 0.814),
+            ('ź', 0.078),
+            ('ż', 0.706),
+        ],
+    },
+    LanguageProfile {
+        code: "en",
+        name: "angielski",
+        frequencies: &[
+            ('a', 8.167),
+            ('b', 1.492),
+            ('c', 2.782),
+            ('d', 4.253),
+            ('e', 12.702),
+            ('f', 2.228),
+            ('g', 2.015),
+            ('h', 6.094),
+            ('i', 6.966),
+            ('j', 0.153),
+            ('k', 0.772),
+            ('l', 4.025),
+            ('m', 2.406),
+            ('n', 6.749),
+            ('o', 7.507),
+            ('p', 1.929),
+            ('q', 0.095),
+            ('r', 5.987),
+            ('s', 6.327),
+            ('t', 9.056),
+            ('u', 2.758),
+            ('v', 0.978),
+            ('w', 2.360),
+            ('x', 0.150),
+            ('y', 1.974),
+            ('z', 0.074),
+        ],
+    },
+    LanguageProfile {
+        code: "de",
+        name: "niemiecki",
+        frequencies: &[
+            ('a', 6.516),
+            ('b', 1.886),
+            ('c', 2.732),
+            ('d', 5.076),
+            ('e', 16.396),
+            ('f', 1.656),
+            ('g', 3.009),
+            ('h', 4.577),
+            ('i', 6.550),
+            ('j', 0.268),
+            ('k', 1.417),
+            ('l', 3.437),
+            ('m', 2.534),
+            ('n', 9.776),
+            ('o', 2.594),
+            ('p', 0.670),
+            ('q', 0.018),
+            ('r', 7.003),
+            ('s', 7.270),
+            ('t', 6.154),
+            ('u', 4.166),
+            ('v', 0.846),
+            ('w', 1.921),
+            ('x', 0.034),
+            ('y', 0.039),
+            ('z', 1.134),
+            ('ä', 0.578),
+            ('ö', 0.443),
+            ('ü', 0.995),
+            ('ß', 0.307),
+        ],
+    },
+];
+
+/// Zwraca wbudowany profil o podanym kodzie języka (bez rozróżniania wielkości liter).
+pub fn find_profile(code: &str) -> Option<&'static LanguageProfile> {
+    PROFILES
+        .iter()
+        .find(|profile| profile.code.eq_ignore_ascii_case(code.trim()))
+}
+
+/// Odległość histogramu od profilu konkretnego języka.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LanguageScore {
+    pub code: &'static str,
+    pub name: &'static str,
+    /// Odległość w zakresie `0.0..=1.0`; im mniejsza, tym lepsze dopasowanie.
+    pub distance: f64,
+}
+
+/// Zamienia histogram na względne częstości (suma równa 1), łącząc wielkie i małe litery
+/// oraz sprowadzając litery z diakrytykami do liter podstawowych (`Ą` → `a`, `ß` → `ss`).
+pub fn relative_frequencies(counts: &BTreeMap<char, usize>) -> BTreeMap<char, f64> {
+    fold_weights(counts.iter().map(|(&letter, &count)| (letter, count as f64)))
+}
+
+/// Sumuje wagi liter po złożeniu wielkości liter i diakrytyków, a potem normalizuje je do 1.
+fn fold_weights(weights: impl Iterator<Item = (char, f64)>) -> BTreeMap<char, f64> {
+    let mut folded = BTreeMap::new();
+    let mut base = Vec::new();
+    for (letter, weight) in weights {
+        for lower in letter.to_lowercase() {
+            base.clear();
+            crate::fold_diacritics(lower, &mut base);
+            for &ch in &base {
+                *folded.entry(ch).or_insert(0.0) += weight;
+            }
+        }
+    }
+
+    let total: f64 = folded.values().sum();
+    if total > 0.0 {
+        for weight in folded.values_mut() {
+            *weight /= total;
+        }
+    }
+    folded
+}
+
+/// Liczy odległość (połowę sumy różnic bezwzględnych) między histogramem a profilem.
+/// Obie strony są porównywane bez diakrytyków, więc wynik nie zależy od trybu liter histogramu.
+pub fn profile_distance(counts: &BTreeMap<char, usize>, profile: &LanguageProfile) -> f64 {
+    let sample = relative_frequencies(counts);
+    let expected = fold_weights(profile.frequencies.iter().copied());
+
+    let mut difference = 0.0;
+    for (letter, p) in &sample {
+        difference += (p - expected.get(letter).copied().unwrap_or(0.0)).abs();
+    }
+    for (letter, q) in &expected {
+        if !sample.contains_key(letter) {
+            difference += q;
+        }
+    }
+    difference / 2.0
+}
+
+/// Porównuje histogram ze wszystkimi wbudowanymi profilami.
+/// Wynik jest posortowany od najlepiej pasującego języka.
+pub fn guess_language(counts: &BTreeMap<char, usize>) -> Vec<LanguageScore> {
+    let mut scores: Vec<LanguageScore> = PROFILES
+        .iter()
+        .map(|profile| LanguageScore {
+            code: profile.code,
+            name: profile.name,
+            distance: profile_distance(counts, profile),
+        })
+        .collect();
+    scores.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+    scores
+}
+
+/// Formatuje ranking języków: linia z najlepszym dopasowaniem, a potem `kod nazwa: odległość`.
+pub fn format_guess(scores: &[LanguageScore]) -> Vec<String> {
+    let mut lines = Vec::with_capacity(scores.len() + 1);
+    if let Some(best) = scores.first() {
+        lines.push(format!("Język: {}", best.name));
+    }
+    for score in scores {
+        lines.push(format!(
+            "{} {}: {:.3}",
+            score.code, score.name, score.distance
+        ));
+    }
+    lines
+}
@@ -1,36 +1,506 @@
 use std::collections::BTreeMap;
-use std::io;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::str::FromStr;
+
+pub mod cipher;
+pub mod language;
+pub mod render;
+
+/// Sposób normalizacji liter przed zliczaniem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LetterMode {
+    /// Tylko litery ASCII (`a`–`z`) bez rozróżniania wielkości; pozostałe znaki są pomijane.
+    #[default]
+    Ascii,
+    /// Wszystkie litery Unicode dokładnie w takiej postaci, w jakiej wystąpiły (`Ą` ≠ `ą`).
+    Exact,
+    /// Wszystkie litery Unicode sprowadzone do małych (`Ą` → `ą`).
+    CaseFolded,
+    /// Małe litery sprowadzone do podstawowej litery łacińskiej (`ą` → `a`, `ß` → `ss`).
+    DiacriticFolded,
+    /// Małe litery, w których znaki łączące są składane z literą bazową (`a` + U+0328 → `ą`).
+    Graphemes,
+}
+
+impl LetterMode {
+    /// Wszystkie tryby w kolejności prezentowanej użytkownikowi.
+    pub fn all() -> [LetterMode; 5] {
+        [
+            LetterMode::Ascii,
+            LetterMode::Exact,
+            LetterMode::CaseFolded,
+            LetterMode::DiacriticFolded,
+            LetterMode::Graphemes,
+        ]
+    }
+
+    /// Nazwa trybu przyjmowana w argumentach programu.
+    pub fn name(self) -> &'static str {
+        match self {
+            LetterMode::Ascii => "ascii",
+            LetterMode::Exact => "exact",
+            LetterMode::CaseFolded => "case-folded",
+            LetterMode::DiacriticFolded => "diacritic-folded",
+            LetterMode::Graphemes => "graphemes",
+        }
+    }
+}
+
+impl FromStr for LetterMode {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let wanted = raw.trim().to_ascii_lowercase();
+        LetterMode::all()
+            .into_iter()
+            .find(|mode| mode.name() == wanted)
+            .ok_or_else(|| format!("Nieznany tryb liter: {}", raw.trim()))
+    }
+}
+
+/// Litery z diakrytykami, które da się zapisać jako literę bazową ze znakiem łączącym:
+/// `(litera złożona, litera bazowa, znak łączący)`.
+const COMPOSED_LETTERS: &[(char, char, char)] = &[
+    ('à', 'a', '\u{0300}'),
+    ('á', 'a', '\u{0301}'),
+    ('â', 'a', '\u{0302}'),
+    ('ã', 'a', '\u{0303}'),
+    ('ä', 'a', '\u{0308}'),
+    ('å', 'a', '\u{030A}'),
+    ('ą', 'a', '\u{0328}'),
+    ('ć', 'c', '\u{0301}'),
+    ('č', 'c', '\u{030C}'),
+    ('ç', 'c', '\u{0327}'),
+    ('ď', 'd', '\u{030C}'),
+    ('è', 'e', '\u{0300}'),
+    ('é', 'e', '\u{0301}'),
+    ('ê', 'e', '\u{0302}'),
+    ('ë', 'e', '\u{0308}'),
+    ('ě', 'e', '\u{030C}'),
+    ('ę', 'e', '\u{0328}'),
+    ('ì', 'i', '\u{0300}'),
+    ('í', 'i', '\u{0301}'),
+    ('î', 'i', '\u{0302}'),
+    ('ï', 'i', '\u{0308}'),
+    ('ń', 'n', '\u{0301}'),
+    ('ñ', 'n', '\u{0303}'),
+    ('ň', 'n', '\u{030C}'),
+    ('ò', 'o', '\u{0300}'),
+    ('ó', 'o', '\u{0301}'),
+    ('ô', 'o', '\u{0302}'),
+    ('õ', 'o', '\u{0303}'),
+    ('ö', 'o', '\u{0308}'),
+    ('ř', 'r', '\u{030C}'),
+    ('ś', 's', '\u{0301}'),
+    ('š', 's', '\u{030C}'),
+    ('ť', 't', '\u{030C}'),
+    ('ù', 'u', '\u{0300}'),
+    ('ú', 'u', '\u{0301}'),
+    ('û', 'u', '\u{0302}'),
+    ('ü', 'u', '\u{0308}'),
+    ('ů', 'u', '\u{030A}'),
+    ('ý', 'y', '\u{0301}'),
+    ('ÿ', 'y', '\u{0308}'),
+    ('ź', 'z', '\u{0301}'),
+    ('ż', 'z', '\u{0307}'),
+    ('ž', 'z', '\u{030C}'),
+];
+
+/// Litery bez rozkładu na znak łączący, które przy składaniu diakrytyków zamieniamy ręcznie.
+const FOLDED_LETTERS: &[(char, &str)] = &[
+    ('ł', "l"),
+    ('đ', "d"),
+    ('ø', "o"),
+    ('ß', "ss"),
+    ('æ', "ae"),
+    ('œ', "oe"),
+];
+
+/// Sprawdza, czy znak należy do bloku znaków łączących (U+0300–U+036F).
+fn is_combining_mark(ch: char) -> bool {
+    ('\u{0300}'..='\u{036F}').contains(&ch)
+}
+
+/// Litera w rozumieniu histogramu: znak alfabetyczny, który nie jest znakiem łączącym.
+fn is_letter(ch: char) -> bool {
+    ch.is_alphabetic() && !is_combining_mark(ch)
+}
+
+/// Składa literę bazową ze znakiem łączącym, jeśli istnieje odpowiednia litera złożona.
+fn compose(base: char, mark: char) -> Option<char> {
+    COMPOSED_LETTERS
+        .iter()
+        .find(|(_, b, m)| *b == base && *m == mark)
+        .map(|(composed, _, _)| *composed)
+}
+
+/// Dopisuje do `out` podstawową literę łacińską (lub ich sekwencję) dla małej litery `ch`.
+fn fold_diacritics(ch: char, out: &mut Vec<char>) {
+    if let Some((_, base, _)) = COMPOSED_LETTERS
+        .iter()
+        .find(|(composed, _, _)| *composed == ch)
+    {
+        out.push(*base);
+    } else if let Some((_, folded)) = FOLDED_LETTERS.iter().find(|(letter, _)| *letter == ch) {
+        out.extend(folded.chars());
+    } else {
+        out.push(ch);
+    }
+}
+
+/// Zbiera małe litery, doklejając znaki łączące do poprzedzającej je litery.
+/// Znaki łączące, dla których nie ma litery złożonej, są pomijane (zostaje sama litera bazowa).
+fn compose_graphemes(text: &str) -> Vec<char> {
+    let mut letters: Vec<char> = Vec::new();
+    let mut after_letter = false;
+
+    for ch in text.chars().flat_map(char::to_lowercase) {
+        if is_combining_mark(ch) {
+            if let (true, Some(last)) = (after_letter, letters.last_mut()) {
+                if let Some(composed) = compose(*last, ch) {
+                    *last = composed;
+                }
+            }
+        } else if is_letter(ch) {
+            letters.push(ch);
+            after_letter = true;
+        } else {
+            after_letter = false;
+        }
+    }
+
+    letters
+}
 
 /// Zamienia wejściowy tekst na wektor znaków zawierający tylko litery alfabetu.
 /// Zwraca `Err("Brak danych")`, gdy po przetworzeniu brak liter.
 pub fn normalize_letters(input: &str) -> Result<Vec<char>, String> {
-    todo!()
+    normalize_letters_with(input, LetterMode::Ascii)
+}
+
+/// Wariant `normalize_letters` z jawnie wybranym trybem normalizacji liter.
+/// Zwraca `Err("Brak danych")`, gdy po przetworzeniu brak liter.
+pub fn normalize_letters_with(input: &str, mode: LetterMode) -> Result<Vec<char>, String> {
+    let trimmed = input.trim();
+
+    let letters: Vec<char> = match mode {
+        LetterMode::Ascii => trimmed
+            .chars()
+            .filter(char::is_ascii_alphabetic)
+            .map(|c| c.to_ascii_lowercase())
+            .collect(),
+        LetterMode::Exact => trimmed.chars().filter(|c| is_letter(*c)).collect(),
+        LetterMode::CaseFolded => trimmed
+            .chars()
+            .flat_map(char::to_lowercase)
+            .filter(|c| is_letter(*c))
+            .collect(),
+        LetterMode::DiacriticFolded => {
+            let mut folded = Vec::new();
+            for ch in trimmed.chars().flat_map(char::to_lowercase) {
+                if is_letter(ch) {
+                    fold_diacritics(ch, &mut folded);
+                }
+            }
+            folded
+        }
+        LetterMode::Graphemes => compose_graphemes(trimmed),
+    };
+
+    if letters.is_empty() {
+        Err("Brak danych".to_string())
+    } else {
+        Ok(letters)
+    }
 }
 
 /// Buduje histogram wystąpień liter na podstawie wektora znaków.
 pub fn build_histogram(letters: &[char]) -> BTreeMap<char, usize> {
-    todo!()
+    let mut counts = BTreeMap::new();
+    for &letter in letters {
+        counts.entry(letter).and_modify(|c| *c += 1).or_insert(1);
+    }
+    counts
 }
 
 /// Formatuje histogram do wypisania na stdout jako linie `litera: liczba`.
 pub fn format_histogram(counts: &BTreeMap<char, usize>) -> Vec<String> {
-    todo!()
+    counts
+        .iter()
+        .map(|(letter, count)| format!("{letter}: {count}"))
+        .collect()
+}
+
+/// Dzieli tekst na słowa (ciągi liter) i normalizuje każde z nich w wybranym trybie.
+/// Litery pomijane przez tryb (w trybie `Ascii` wszystkie spoza `a`–`z`) też rozdzielają słowa,
+/// więc n-gramy nie łączą liter stojących po obu stronach pominiętego znaku (`kąt` → `k`, `t`).
+/// Zwraca `Err("Brak danych")`, gdy w tekście nie ma żadnej litery.
+pub fn normalize_words_with(input: &str, mode: LetterMode) -> Result<Vec<Vec<char>>, String> {
+    let is_word_char = |c: char| match mode {
+        LetterMode::Ascii => c.is_ascii_alphabetic(),
+        _ => c.is_alphabetic() || is_combining_mark(c),
+    };
+    let words: Vec<Vec<char>> = input
+        .split(|c: char| !is_word_char(c))
+        .filter_map(|word| normalize_letters_with(word, mode).ok())
+        .collect();
+
+    if words.is_empty() {
+        Err("Brak danych".to_string())
+    } else {
+        Ok(words)
+    }
+}
+
+/// Buduje histogram n-gramów liter. N-gramy nie przekraczają granic słów.
+pub fn build_ngram_histogram(words: &[Vec<char>], n: usize) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    if n == 0 {
+        return counts;
+    }
+
+    for word in words {
+        for window in word.windows(n) {
+            let ngram: String = window.iter().collect();
+            counts.entry(ngram).and_modify(|c| *c += 1).or_insert(1);
+        }
+    }
+    counts
+}
+
+/// Formatuje histogram n-gramów jako linie `ngram: liczba`.
+pub fn format_ngram_histogram(counts: &BTreeMap<String, usize>) -> Vec<String> {
+    counts
+        .iter()
+        .map(|(ngram, count)| format!("{ngram}: {count}"))
+        .collect()
+}
+
+/// Ustawienia programu odczytane z argumentów wiersza poleceń.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// Tryb normalizacji liter (`--mode`).
+    pub mode: LetterMode,
+    /// Długość zliczanych n-gramów (`--ngram`); `1` oznacza pojedyncze litery.
+    pub ngram: usize,
+    /// Czy zamiast histogramu wypisać ranking języków (`--guess`).
+    pub guess: bool,
+    /// Szyfr do złamania zamiast budowania histogramu (`--crack`).
+    pub crack: Option<cipher::CipherKind>,
+    /// Kod języka, którego rozkład liter jest oczekiwany w tekście jawnym (`--lang`).
+    pub language: String,
+    /// Największa sprawdzana długość klucza Vigenère'a (`--max-key`).
+    pub max_key_length: usize,
+    /// Kolejność wierszy histogramu (`--sort alpha|freq`).
+    pub sort: render::SortOrder,
+    /// Format wypisywanego histogramu (`--format`).
+    pub format: render::OutputFormat,
+    /// Długość najdłuższego słupka w formacie `bars` (`--width`).
+    pub bar_width: usize,
+    /// Pliki wejściowe; pusta lista lub `-` oznacza stdin.
+    pub files: Vec<String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            mode: LetterMode::default(),
+            ngram: 1,
+            guess: false,
+            crack: None,
+            language: "en".to_string(),
+            max_key_length: 12,
+            sort: render::SortOrder::default(),
+            format: render::OutputFormat::default(),
+            bar_width: 50,
+            files: Vec::new(),
+        }
+    }
 }
 
 /// Główna logika: przygotowuje wynik do wypisania lub zwraca komunikat błędu.
 pub fn run_from_str(input: &str) -> Result<Vec<String>, String> {
-    todo!()
+    run_from_str_with(input, LetterMode::Ascii)
 }
 
-pub fn main() {
-    let mut buffer = String::new();
+/// Wariant `run_from_str` z jawnie wybranym trybem normalizacji liter.
+pub fn run_from_str_with(input: &str, mode: LetterMode) -> Result<Vec<String>, String> {
+    match normalize_letters_with(input, mode) {
+        Ok(letters) => Ok(format_histogram(&build_histogram(&letters))),
+        Err(message) => Err(message),
+    }
+}
+
+/// Wariant `run_from_str` sterowany wszystkimi ustawieniami programu.
+pub fn run_with_options(input: &str, options: &Options) -> Result<Vec<String>, String> {
+    if let Some(kind) = options.crack {
+        let profile = language::find_profile(&options.language)
+            .ok_or_else(|| format!("Nieznany język: {}", options.language))?;
+        return match kind {
+            cipher::CipherKind::Caesar => Ok(cipher::format_caesar(&cipher::crack_caesar(
+                input.trim(),
+                profile,
+            )?)),
+            cipher::CipherKind::Vigenere => {
+                let guess = cipher::crack_vigenere(input.trim(), options.max_key_length, profile)?;
+                Ok(cipher::format_vigenere(&guess))
+            }
+        };
+    }
+
+    if options.guess {
+        // Profile języków porównujemy bez diakrytyków, więc tryb z `--mode` nie ma tu znaczenia.
+        let letters = normalize_letters_with(input, LetterMode::DiacriticFolded)?;
+        let scores = language::guess_language(&build_histogram(&letters));
+        return Ok(language::format_guess(&scores));
+    }
+
+    if options.ngram == 1 {
+        return match normalize_letters_with(input, options.mode) {
+            Ok(letters) => Ok(render::render_histogram(
+                &build_histogram(&letters),
+                options.sort,
+                options.format,
+                options.bar_width,
+            )),
+            Err(message) => Err(message),
+        };
+    }
+
+    let words = normalize_words_with(input, options.mode)?;
+    let counts = build_ngram_histogram(&words, options.ngram);
+    if counts.is_empty() {
+        return Err("Brak danych".to_string());
+    }
+    Ok(render::render_histogram(
+        &counts,
+        options.sort,
+        options.format,
+        options.bar_width,
+    ))
+}
+
+/// Wczytuje całe wejście: kolejno wszystkie pliki albo stdin, gdy lista jest pusta.
+/// Nazwa `-` na liście również oznacza stdin.
+pub fn read_input(files: &[String]) -> Result<String, String> {
+    let mut text = String::new();
+
+    if files.is_empty() {
+        return read_stdin(text);
+    }
+
+    for path in files {
+        if path == "-" {
+            text = read_stdin(text)?;
+        } else {
+            match fs::read_to_string(path) {
+                Ok(content) => text.push_str(&content),
+                Err(err) => return Err(format!("Nie udało się odczytać pliku {path}: {err}")),
+            }
+        }
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+    }
 
-    if io::stdin().read_line(&mut buffer).is_err() {
-        println!("Brak danych");
-        return;
+    Ok(text)
+}
+
+/// Dopisuje do bufora całą zawartość stdin.
+fn read_stdin(mut text: String) -> Result<String, String> {
+    match io::stdin().lock().read_to_string(&mut text) {
+        Ok(_) => Ok(text),
+        Err(_) => Err("Brak danych".to_string()),
     }
+}
+
+/// Zwraca wartość flagi: podaną po `=` albo kolejny argument.
+fn take_value<I>(flag: &str, inline: Option<&str>, rest: &mut I) -> Result<String, String>
+where
+    I: Iterator<Item = String>,
+{
+    match inline {
+        Some(value) => Ok(value.to_string()),
+        None => rest
+            .next()
+            .ok_or_else(|| format!("Brak wartości dla {flag}")),
+    }
+}
+
+/// Odczytuje ustawienia z argumentów programu:
+/// `--mode <tryb>`, `--ngram <1-3>`, `--guess`, `--crack <caesar|vigenere>`, `--lang <kod>`
+/// `--max-key <n>`, `--sort alpha|freq`, `--format lines|bars|percent|csv|json`, `--width <n>`
+/// (wartości można też podać po `=`). Pozostałe argumenty to ścieżki plików wejściowych.
+pub fn parse_args<I>(args: I) -> Result<Options, String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut options = Options::default();
+    let mut args = args.into_iter().map(|arg| arg.as_ref().to_string());
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) => (flag, Some(value)),
+            None => (arg.as_str(), None),
+        };
+
+        match flag {
+            "--mode" => options.mode = take_value(flag, inline, &mut args)?.parse()?,
+            "--ngram" => {
+                let value = take_value(flag, inline, &mut args)?;
+                options.ngram = match value.trim().parse::<usize>() {
+                    Ok(n @ 1..=3) => n,
+                    _ => return Err(format!("Niepoprawna długość n-gramu: {value}")),
+                };
+            }
+            "--guess" if inline.is_none() => options.guess = true,
+            "--crack" => options.crack = Some(take_value(flag, inline, &mut args)?.parse()?),
+            "--lang" => options.language = take_value(flag, inline, &mut args)?,
+            "--max-key" => {
+                let value = take_value(flag, inline, &mut args)?;
+                options.max_key_length = match value.trim().parse::<usize>() {
+                    Ok(n) if n >= 1 => n,
+                    _ => return Err(format!("Niepoprawna długość klucza: {value}")),
+                };
+            }
+            "--sort" => options.sort = take_value(flag, inline, &mut args)?.parse()?,
+            "--format" => options.format = take_value(flag, inline, &mut args)?.parse()?,
+            "--width" => {
+                let value = take_value(flag, inline, &mut args)?;
+                options.bar_width = match value.trim().parse::<usize>() {
+                    Ok(n) if n >= 1 => n,
+                    _ => return Err(format!("Niepoprawna szerokość: {value}")),
+                };
+            }
+            _ if arg == "-" || !arg.starts_with("--") => options.files.push(arg.clone()),
+            _ => return Err(format!("Nieznany argument: {arg}")),
+        }
+    }
+
+    Ok(options)
+}
+
+pub fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            println!("{}", message);
+            return;
+        }
+    };
+
+    let buffer = match read_input(&options.files) {
+        Ok(buffer) => buffer,
+        Err(message) => {
+            println!("{}", message);
+            return;
+        }
+    };
 
-    match run_from_str(&buffer) {
+    match run_with_options(&buffer, &options) {
         Ok(lines) => {
             for line in lines {
                 println!("{}", line);
@@ -0,0 +1,160 @@
+//! Sortowanie i formatowanie histogramów: linie, wykres słupkowy, procenty, CSV i JSON.
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Kolejność wierszy w wyniku.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    /// Rosnąco według klucza (litery lub n-gramu).
+    #[default]
+    Alphabetical,
+    /// Malejąco według liczby wystąpień; remisy rozstrzyga kolejność alfabetyczna.
+    Frequency,
+}
+
+impl FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "alpha" => Ok(SortOrder::Alphabetical),
+            "freq" => Ok(SortOrder::Frequency),
+            _ => Err(format!("Nieznana kolejność: {}", raw.trim())),
+        }
+    }
+}
+
+/// Format wypisywanego histogramu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Linie `klucz: liczba` (format podstawowy).
+    #[default]
+    Lines,
+    /// Wykres słupkowy ze znaków `#` przeskalowany do zadanej szerokości.
+    Bars,
+    /// Linie `klucz: procent%`.
+    Percent,
+    /// CSV z nagłówkiem `klucz,liczba,procent`.
+    Csv,
+    /// Tablica JSON obiektów `{"klucz", "liczba", "procent"}`.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "lines" => Ok(OutputFormat::Lines),
+            "bars" => Ok(OutputFormat::Bars),
+            "percent" => Ok(OutputFormat::Percent),
+            "csv" => Ok(OutputFormat::Csv),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("Nieznany format: {}", raw.trim())),
+        }
+    }
+}
+
+/// Zwraca wpisy histogramu w wybranej kolejności.
+pub fn sorted_entries<K: Ord>(counts: &BTreeMap<K, usize>, order: SortOrder) -> Vec<(&K, usize)> {
+    let mut entries: Vec<(&K, usize)> = counts.iter().map(|(key, &count)| (key, count)).collect();
+    if order == SortOrder::Frequency {
+        // Sortowanie stabilne: przy równych liczbach zostaje kolejność z `BTreeMap`.
+        entries.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+    }
+    entries
+}
+
+/// Formatuje histogram w wybranej kolejności i formacie.
+/// `bar_width` to długość najdłuższego słupka w formacie `Bars`.
+pub fn render_histogram<K: Ord + Display>(
+    counts: &BTreeMap<K, usize>,
+    order: SortOrder,
+    format: OutputFormat,
+    bar_width: usize,
+) -> Vec<String> {
+    let entries = sorted_entries(counts, order);
+    let total: usize = counts.values().sum();
+    let percent = |count: usize| {
+        if total == 0 {
+            0.0
+        } else {
+            count as f64 * 100.0 / total as f64
+        }
+    };
+
+    match format {
+        OutputFormat::Lines => entries
+            .iter()
+            .map(|(key, count)| format!("{key}: {count}"))
+            .collect(),
+        OutputFormat::Percent => entries
+            .iter()
+            .map(|(key, count)| format!("{key}: {:.2}%", percent(*count)))
+            .collect(),
+        OutputFormat::Bars => {
+            let max = entries.iter().map(|(_, count)| *count).max().unwrap_or(0);
+            let key_width = entries
+                .iter()
+                .map(|(key, _)| key.to_string().chars().count())
+                .max()
+                .unwrap_or(0);
+            entries
+                .iter()
+                .map(|(key, count)| {
+                    let bar = "#".repeat(bar_length(*count, max, bar_width));
+                    let key = key.to_string();
+                    let padding = " ".repeat(key_width - key.chars().count());
+                    format!("{key}{padding} | {bar} {count}")
+                })
+                .collect()
+        }
+        OutputFormat::Csv => {
+            let mut lines = vec!["klucz,liczba,procent".to_string()];
+            lines.extend(
+                entries
+                    .iter()
+                    .map(|(key, count)| format!("{key},{count},{:.2}", percent(*count))),
+            );
+            lines
+        }
+        OutputFormat::Json => {
+            let mut lines = vec!["[".to_string()];
+            for (index, (key, count)) in entries.iter().enumerate() {
+                let separator = if index + 1 < entries.len() { "," } else { "" };
+                lines.push(format!(
+                    "  {{\"klucz\": {}, \"liczba\": {count}, \"procent\": {:.2}}}{separator}",
+                    json_string(&key.to_string()),
+                    percent(*count)
+                ));
+            }
+            lines.push("]".to_string());
+            lines
+        }
+    }
+}
+
+/// Długość słupka proporcjonalna do `count`; niezerowe liczby mają co najmniej jeden znak.
+fn bar_length(count: usize, max: usize, width: usize) -> usize {
+    if count == 0 || max == 0 {
+        return 0;
+    }
+    ((count * width + max / 2) / max).max(1)
+}
+
+/// Zapisuje tekst jako literał JSON, escapując cudzysłowy, backslashe i znaki sterujące.
+fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
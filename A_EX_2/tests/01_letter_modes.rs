@@ -0,0 +1,51 @@
+use a_ex_2::{
+    build_histogram, format_histogram, normalize_letters, normalize_letters_with, run_from_str,
+    run_from_str_with, LetterMode,
+};
+
+fn letters(input: &str, mode: LetterMode) -> String {
+    normalize_letters_with(input, mode).expect("input has letters").into_iter().collect()
+}
+
+#[test]
+fn default_mode_keeps_only_ascii_letters() {
+    assert_eq!(normalize_letters(" Ala ma kota! "), Ok(vec!['a', 'l', 'a', 'm', 'a', 'k', 'o', 't', 'a']));
+    assert_eq!(normalize_letters("123 !?"), Err("Brak danych".to_string()));
+    assert_eq!(normalize_letters("ąę"), Err("Brak danych".to_string()));
+    assert_eq!(run_from_str("Abba\n"), Ok(vec!["a: 2".to_string(), "b: 2".to_string()]));
+}
+
+#[test]
+fn every_mode_normalizes_letters() {
+    let text = "Ąę ß";
+    assert_eq!(letters(text, LetterMode::Exact), "Ąęß");
+    assert_eq!(letters(text, LetterMode::CaseFolded), "ąęß");
+    assert_eq!(letters(text, LetterMode::DiacriticFolded), "aess");
+    assert_eq!(letters("Łódź", LetterMode::DiacriticFolded), "lodz");
+}
+
+#[test]
+fn graphemes_mode_composes_combining_marks() {
+    // `a` + U+0328 to `ą`, a znak łączący bez litery złożonej jest pomijany.
+    assert_eq!(letters("A\u{0328}b\u{0328}", LetterMode::Graphemes), "ąb");
+    assert_eq!(letters("a\u{0328}", LetterMode::CaseFolded), "a");
+    assert_eq!(normalize_letters_with("\u{0328}", LetterMode::Graphemes), Err("Brak danych".to_string()));
+}
+
+#[test]
+fn histogram_counts_each_letter() {
+    let counts = build_histogram(&['b', 'a', 'b']);
+    assert_eq!(format_histogram(&counts), vec!["a: 1", "b: 2"]);
+    assert_eq!(
+        run_from_str_with("Zażółć", LetterMode::CaseFolded).unwrap(),
+        vec!["a: 1", "z: 1", "ó: 1", "ć: 1", "ł: 1", "ż: 1"]
+    );
+}
+
+#[test]
+fn mode_names_round_trip() {
+    for mode in LetterMode::all() {
+        assert_eq!(mode.name().parse::<LetterMode>(), Ok(mode));
+    }
+    assert_eq!("nope".parse::<LetterMode>(), Err("Nieznany tryb liter: nope".to_string()));
+}
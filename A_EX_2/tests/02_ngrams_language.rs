@@ -0,0 +1,76 @@
+use a_ex_2::language::{find_profile, guess_language, profile_distance, relative_frequencies};
+use a_ex_2::{
+    build_histogram, build_ngram_histogram, normalize_words_with, parse_args, run_with_options,
+    LetterMode,
+};
+
+fn bigrams(input: &str, mode: LetterMode) -> Vec<String> {
+    let words = normalize_words_with(input, mode).expect("input has letters");
+    build_ngram_histogram(&words, 2).into_keys().collect()
+}
+
+#[test]
+fn ngrams_do_not_cross_word_boundaries() {
+    let words = normalize_words_with("Ala ma, kota!", LetterMode::Ascii).unwrap();
+    let counts = build_ngram_histogram(&words, 2);
+    assert_eq!(counts.keys().collect::<Vec<_>>(), vec!["al", "ko", "la", "ma", "ot", "ta"]);
+    assert!(build_ngram_histogram(&words, 0).is_empty());
+    assert_eq!(normalize_words_with("123 !?", LetterMode::Ascii).unwrap_err(), "Brak danych");
+}
+
+#[test]
+fn ascii_mode_splits_words_at_dropped_letters() {
+    // `ą` jest pomijane w trybie ASCII, więc nie może powstać bigram `kt`.
+    assert_eq!(bigrams("kąt", LetterMode::Ascii), Vec::<String>::new());
+    assert_eq!(bigrams("kąty", LetterMode::Ascii), vec!["ty"]);
+    assert_eq!(bigrams("kąt", LetterMode::CaseFolded), vec!["ką", "ąt"]);
+    assert_eq!(bigrams("kąt", LetterMode::DiacriticFolded), vec!["at", "ka"]);
+}
+
+#[test]
+fn ngram_option_renders_counts() {
+    let options = parse_args(["--ngram", "3"]).unwrap();
+    let lines = run_with_options("anana", &options).unwrap();
+    assert_eq!(lines, vec!["ana: 2", "nan: 1"]);
+    assert_eq!(parse_args(["--ngram=4"]).unwrap_err(), "Niepoprawna długość n-gramu: 4");
+}
+
+#[test]
+fn relative_frequencies_fold_case_and_diacritics() {
+    let counts = build_histogram(&['A', 'ą', 'b', 'ß']);
+    let frequencies = relative_frequencies(&counts);
+    assert_eq!(frequencies.keys().collect::<Vec<_>>(), vec![&'a', &'b', &'s']);
+    assert!((frequencies[&'a'] - 0.4).abs() < 1e-9);
+    assert!((frequencies[&'s'] - 0.4).abs() < 1e-9);
+}
+
+#[test]
+fn guess_ignores_letter_mode() {
+    let text = "Zażółć gęślą jaźń, chrząszcz brzmi w trzcinie w Szczebrzeszynie";
+    let mut first = Vec::new();
+    for mode in LetterMode::all() {
+        let mut options = parse_args(["--guess"]).unwrap();
+        options.mode = mode;
+        let lines = run_with_options(text, &options).unwrap();
+        assert_eq!(lines[0], "Język: polski");
+        if first.is_empty() {
+            first = lines;
+        } else {
+            assert_eq!(lines, first, "tryb {}", mode.name());
+        }
+    }
+}
+
+#[test]
+fn guess_ranks_german_text() {
+    let text = "Der schnelle braune Fuchs springt über den faulen Hund und größere Bären";
+    let letters = a_ex_2::normalize_letters_with(text, LetterMode::DiacriticFolded).unwrap();
+    let scores = guess_language(&build_histogram(&letters));
+    assert_eq!(scores[0].code, "de");
+    assert!(scores.windows(2).all(|pair| pair[0].distance <= pair[1].distance));
+
+    let german = find_profile("DE").unwrap();
+    let exact = build_histogram(&a_ex_2::normalize_letters_with(text, LetterMode::Exact).unwrap());
+    let distance = profile_distance(&exact, german);
+    assert!((distance - scores[0].distance).abs() < 1e-9);
+}
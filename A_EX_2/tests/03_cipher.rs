@@ -0,0 +1,78 @@
+use a_ex_2::cipher::{
+    crack_caesar, crack_vigenere, estimate_key_lengths, kasiski_votes, pick_key_length,
+    shift_letters, vigenere_decrypt, vigenere_encrypt, CipherKind, KeyLengthCandidate,
+};
+use a_ex_2::language::find_profile;
+use a_ex_2::normalize_letters;
+
+const PLAINTEXT: &str = "It was the best of times, it was the worst of times, it was the age of wisdom, \
+    it was the age of foolishness, it was the epoch of belief, it was the epoch of incredulity, \
+    it was the season of Light, it was the season of Darkness, it was the spring of hope, \
+    it was the winter of despair, we had everything before us, we had nothing before us, \
+    we were all going direct to Heaven, we were all going direct the other way";
+
+fn candidate(length: usize, coincidence: f64, kasiski_votes: usize) -> KeyLengthCandidate {
+    KeyLengthCandidate { length, coincidence, kasiski_votes }
+}
+
+#[test]
+fn shifting_keeps_case_and_other_characters() {
+    assert_eq!(shift_letters("Zebra, 42!", 3), "Cheud, 42!");
+    let encrypted = vigenere_encrypt("Attack at dawn!", "Lemon").unwrap();
+    assert_eq!(encrypted, "Lxfopv ef rnhr!");
+    assert_eq!(vigenere_decrypt(&encrypted, "LEMON").unwrap(), "Attack at dawn!");
+    assert_eq!(vigenere_encrypt("abc", "k3y").unwrap_err(), "Niepoprawny klucz: k3y");
+    assert_eq!("cezar".parse::<CipherKind>(), Ok(CipherKind::Caesar));
+}
+
+#[test]
+fn caesar_is_cracked_with_language_profile() {
+    let english = find_profile("en").unwrap();
+    let guess = crack_caesar("Wkh txlfn eurzq ira mxpsv ryhu wkh odcb grj", english).unwrap();
+    assert_eq!(guess.shift, 3);
+    assert_eq!(guess.plaintext, "The quick brown fox jumps over the lazy dog");
+    assert_eq!(crack_caesar("123", english).unwrap_err(), "Brak danych");
+}
+
+#[test]
+fn kasiski_votes_decide_within_coincidence_band() {
+    // Długość 12 ma najwyższy indeks, ale 6 ma podobny i więcej głosów Kasiskiego.
+    let candidates = [
+        candidate(3, 0.052, 36),
+        candidate(6, 0.076, 36),
+        candidate(12, 0.082, 20),
+    ];
+    assert_eq!(pick_key_length(&candidates), Some(6));
+
+    // Głosy wygrywają także z krótszą długością z pasma.
+    let candidates = [candidate(4, 0.070, 3), candidate(8, 0.072, 9)];
+    assert_eq!(pick_key_length(&candidates), Some(8));
+
+    // Przy remisie głosów wybierana jest najkrótsza długość.
+    let candidates = [candidate(5, 0.070, 0), candidate(10, 0.072, 0)];
+    assert_eq!(pick_key_length(&candidates), Some(5));
+    assert_eq!(pick_key_length(&[]), None);
+}
+
+#[test]
+fn kasiski_votes_count_divisible_distances() {
+    let letters: Vec<char> = "abcxxabcyyyyabc".chars().collect();
+    let votes = kasiski_votes(&letters, 7);
+    // Powtórzenia `abc` w odległościach 5 i 7.
+    assert_eq!(votes.get(&5), Some(&1));
+    assert_eq!(votes.get(&7), Some(&1));
+    assert_eq!(votes.get(&2), None);
+}
+
+#[test]
+fn vigenere_is_cracked_from_long_text() {
+    let ciphertext = vigenere_encrypt(PLAINTEXT, "lemons").unwrap();
+    let guess = crack_vigenere(&ciphertext, 12, find_profile("en").unwrap()).unwrap();
+    assert_eq!(guess.key, "lemons");
+    assert_eq!(guess.plaintext, PLAINTEXT);
+
+    let letters = normalize_letters(&ciphertext).unwrap();
+    let candidates = estimate_key_lengths(&letters, 12);
+    assert_eq!(candidates.len(), 12);
+    assert_eq!(guess.candidates, candidates);
+}
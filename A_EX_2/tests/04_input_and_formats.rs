@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use a_ex_2::render::{render_histogram, sorted_entries, OutputFormat, SortOrder};
+use a_ex_2::{parse_args, read_input, run_with_options};
+
+fn counts() -> BTreeMap<char, usize> {
+    BTreeMap::from([('a', 3), ('b', 1), ('c', 4)])
+}
+
+#[test]
+fn frequency_order_breaks_ties_alphabetically() {
+    let counts = BTreeMap::from([('b', 2), ('a', 2), ('c', 5)]);
+    let keys: Vec<char> = sorted_entries(&counts, SortOrder::Frequency).into_iter().map(|(k, _)| *k).collect();
+    assert_eq!(keys, vec!['c', 'a', 'b']);
+}
+
+#[test]
+fn every_output_format() {
+    let render = |format| render_histogram(&counts(), SortOrder::Alphabetical, format, 8);
+    assert_eq!(render(OutputFormat::Lines), vec!["a: 3", "b: 1", "c: 4"]);
+    assert_eq!(render(OutputFormat::Bars), vec!["a | ###### 3", "b | ## 1", "c | ######## 4"]);
+    assert_eq!(render(OutputFormat::Percent), vec!["a: 37.50%", "b: 12.50%", "c: 50.00%"]);
+    assert_eq!(render(OutputFormat::Csv), vec!["klucz,liczba,procent", "a,3,37.50", "b,1,12.50", "c,4,50.00"]);
+    assert_eq!(render(OutputFormat::Json), vec![
+        "[",
+        "  {\"klucz\": \"a\", \"liczba\": 3, \"procent\": 37.50},",
+        "  {\"klucz\": \"b\", \"liczba\": 1, \"procent\": 12.50},",
+        "  {\"klucz\": \"c\", \"liczba\": 4, \"procent\": 50.00}",
+        "]",
+    ]);
+}
+
+#[test]
+fn bars_align_multi_character_keys() {
+    let options = parse_args(["--ngram=2", "--format", "bars", "--width", "4", "--sort", "freq"]).unwrap();
+    let lines = run_with_options("aaa ab", &options).unwrap();
+    assert_eq!(lines, vec!["aa | #### 2", "ab | ## 1"]);
+}
+
+#[test]
+fn whole_input_is_read_from_every_file() {
+    let dir = std::env::temp_dir().join(format!("a_ex_2_input_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let first = dir.join("first.txt");
+    let second = dir.join("second.txt");
+    fs::write(&first, "Ala\nma").unwrap();
+    fs::write(&second, "kota\n").unwrap();
+    let files = vec![first.display().to_string(), second.display().to_string()];
+
+    let text = read_input(&files).unwrap();
+    assert_eq!(text, "Ala\nma\nkota\n");
+
+    let missing = dir.join("missing.txt").display().to_string();
+    let err = read_input(std::slice::from_ref(&missing)).unwrap_err();
+    assert!(err.starts_with(&format!("Nie udało się odczytać pliku {missing}")));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn arguments_select_format_and_files() {
+    let options = parse_args(["--format=csv", "plik.txt", "-"]).unwrap();
+    assert_eq!(options.format, OutputFormat::Csv);
+    assert_eq!(options.files, vec!["plik.txt", "-"]);
+    assert_eq!(parse_args(["--width", "0"]).unwrap_err(), "Niepoprawna szerokość: 0");
+    assert_eq!(parse_args(["--format", "xml"]).unwrap_err(), "Nieznany format: xml");
+    assert_eq!(parse_args(["--sort"]).unwrap_err(), "Brak wartości dla --sort");
+    assert_eq!(parse_args(["--verbose"]).unwrap_err(), "Nieznany argument: --verbose");
+}
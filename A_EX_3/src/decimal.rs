@@ -0,0 +1,270 @@
+//! Dokładne liczby dziesiętne (bez binarnych liczb zmiennoprzecinkowych) z kontrolą przepełnienia.
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// Największa liczba cyfr po przecinku przechowywana w wyniku.
+pub const MAX_SCALE: u32 = 18;
+
+/// Liczba cyfr po przecinku, z jaką liczone jest dzielenie liczb niecałkowitych.
+const DIVISION_SCALE: u32 = 10;
+
+/// Zakres wartości, które może przyjmować wynik i suma sesji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberMode {
+    /// Wartości muszą mieścić się w zakresie `i32` (jak w pierwotnym sumatorze).
+    #[default]
+    Standard,
+    /// Szeroki tryb: ograniczeniem jest tylko 128-bitowa mantysa.
+    Wide,
+}
+
+impl NumberMode {
+    /// Zwraca `None`, jeśli wartość wykracza poza zakres trybu.
+    pub fn check(self, value: Decimal) -> Option<Decimal> {
+        match self {
+            NumberMode::Wide => Some(value),
+            NumberMode::Standard => {
+                let factor = pow10(value.scale)?;
+                let low = (i32::MIN as i128).checked_mul(factor)?;
+                let high = (i32::MAX as i128).checked_mul(factor)?;
+                (low..=high).contains(&value.mantissa).then_some(value)
+            }
+        }
+    }
+}
+
+/// Liczba dziesiętna `mantissa / 10^scale`, np. `12.50` to mantysa `1250` i skala `2`.
+///
+/// Skala wyniku dodawania i odejmowania to większa ze skal argumentów, więc `12.50 + 1`
+/// daje `13.50`. Porównania uwzględniają wartość, a nie zapis (`1.0 == 1`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+fn pow10(exponent: u32) -> Option<i128> {
+    10i128.checked_pow(exponent)
+}
+
+impl Decimal {
+    pub const ZERO: Decimal = Decimal {
+        mantissa: 0,
+        scale: 0,
+    };
+
+    /// Tworzy liczbę z mantysy i liczby cyfr po przecinku.
+    pub fn new(mantissa: i128, scale: u32) -> Option<Self> {
+        (scale <= MAX_SCALE).then_some(Self { mantissa, scale })
+    }
+
+    pub fn from_int(value: i128) -> Self {
+        Self {
+            mantissa: value,
+            scale: 0,
+        }
+    }
+
+    pub fn scale(self) -> u32 {
+        self.scale
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Zwiększa skalę bez zmiany wartości (`1.5` → `1.500`).
+    fn rescale(self, scale: u32) -> Option<Self> {
+        if scale < self.scale {
+            return None;
+        }
+        let mantissa = self.mantissa.checked_mul(pow10(scale - self.scale)?)?;
+        Self::new(mantissa, scale)
+    }
+
+    /// Sprowadza obie liczby do wspólnej skali.
+    fn align(self, other: Self) -> Option<(i128, i128, u32)> {
+        let scale = self.scale.max(other.scale);
+        Some((
+            self.rescale(scale)?.mantissa,
+            other.rescale(scale)?.mantissa,
+            scale,
+        ))
+    }
+
+    /// Usuwa końcowe zera części ułamkowej, nie schodząc poniżej `min_scale`.
+    fn trim(mut self, min_scale: u32) -> Self {
+        while self.scale > min_scale && self.mantissa % 10 == 0 {
+            self.mantissa /= 10;
+            self.scale -= 1;
+        }
+        self
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let (a, b, scale) = self.align(other)?;
+        Self::new(a.checked_add(b)?, scale)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let (a, b, scale) = self.align(other)?;
+        Self::new(a.checked_sub(b)?, scale)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Self::new(self.mantissa.checked_neg()?, self.scale)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let product = Self {
+            mantissa: self.mantissa.checked_mul(other.mantissa)?,
+            scale: self.scale + other.scale,
+        };
+        let min_scale = self.scale.max(other.scale);
+        let trimmed = product.trim(min_scale);
+        if trimmed.scale > MAX_SCALE {
+            trimmed.round_to(MAX_SCALE)
+        } else {
+            Some(trimmed)
+        }
+    }
+
+    /// Dzielenie. Dla dwóch liczb całkowitych działa jak `/` w Ruście (z obcięciem),
+    /// w pozostałych przypadkach wynik ma do 10 cyfr po przecinku (obcięty w stronę zera).
+    /// Zwraca `None` przy dzieleniu przez zero lub przepełnieniu.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        if self.scale == 0 && other.scale == 0 {
+            return Some(Self::from_int(self.mantissa.checked_div(other.mantissa)?));
+        }
+
+        let min_scale = self.scale.max(other.scale);
+        let (a, b, _) = self.align(other)?;
+        let numerator = a.checked_mul(pow10(DIVISION_SCALE)?)?;
+        Some(Self::new(numerator.checked_div(b)?, DIVISION_SCALE)?.trim(min_scale))
+    }
+
+    /// Reszta z dzielenia ze znakiem dzielnej (jak `%` w Ruście).
+    pub fn checked_rem(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let (a, b, scale) = self.align(other)?;
+        Self::new(a.checked_rem(b)?, scale)
+    }
+
+    /// Dzieli przez `other` i zaokrągla wynik do `scale` cyfr (połówki od zera).
+    pub fn div_to_scale(self, other: Self, scale: u32) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let (a, b, _) = self.align(other)?;
+        let numerator = a.checked_mul(pow10(scale + 1)?)?;
+        let tenths = numerator.checked_div(b)?;
+        Self::new(round_last_digit(tenths)?, scale)
+    }
+
+    /// Zaokrągla do `scale` cyfr po przecinku (połówki od zera).
+    fn round_to(self, scale: u32) -> Option<Self> {
+        if self.scale <= scale {
+            return Some(self);
+        }
+        let divisor = pow10(self.scale - scale - 1)?;
+        Self::new(round_last_digit(self.mantissa / divisor)?, scale)
+    }
+}
+
+/// Usuwa ostatnią cyfrę liczby, zaokrąglając połówki od zera.
+fn round_last_digit(value: i128) -> Option<i128> {
+    let rest = value % 10;
+    let base = value / 10;
+    if rest >= 5 {
+        base.checked_add(1)
+    } else if rest <= -5 {
+        base.checked_sub(1)
+    } else {
+        Some(base)
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Porównujemy osobno część całkowitą i ułamkową sprowadzoną do `MAX_SCALE`,
+        // dzięki czemu wyrównywanie skal nie może się przepełnić.
+        let split = |d: &Decimal| {
+            let factor = 10i128.pow(d.scale);
+            (
+                d.mantissa / factor,
+                (d.mantissa % factor) * 10i128.pow(MAX_SCALE - d.scale),
+            )
+        };
+        split(self).cmp(&split(other))
+    }
+}
+
+impl From<i32> for Decimal {
+    fn from(value: i32) -> Self {
+        Self::from_int(value as i128)
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.scale == 0 {
+            return write!(f, "{}", self.mantissa);
+        }
+
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        let padded = format!("{digits:0>width$}", width = scale + 1);
+        let (whole, fraction) = padded.split_at(padded.len() - scale);
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        write!(f, "{sign}{whole}.{fraction}")
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = String;
+
+    /// Parsuje zapis `123`, `12.50` lub `-0.5`; separator dziesiętny to kropka.
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let text = raw.trim();
+        let (negative, unsigned) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text),
+        };
+        let (whole, fraction) = match unsigned.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (unsigned, None),
+        };
+        let is_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+        if !is_digits(whole) || fraction.is_some_and(|part| !is_digits(part)) {
+            return Err(format!("Niepoprawna liczba: {text}"));
+        }
+        let fraction = fraction.unwrap_or("");
+
+        let scale = fraction.len() as u32;
+        let mantissa = format!("{whole}{fraction}")
+            .parse::<i128>()
+            .map_err(|_| format!("Liczba poza zakresem: {text}"))?;
+        let mantissa = if negative { -mantissa } else { mantissa };
+        Decimal::new(mantissa, scale).ok_or_else(|| format!("Za dużo cyfr po przecinku: {text}"))
+    }
+}
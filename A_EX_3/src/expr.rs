@@ -0,0 +1,276 @@
+//! Kalkulator wyrażeń arytmetycznych dla sumatora.
+//!
+//! Gramatyka (od najniższego priorytetu):
+//! ```text
+//! wyrażenie := składnik (('+' | '-') składnik)*
+//! składnik  := czynnik (('*' | '/' | '%') czynnik)*
+//! czynnik   := ('-' | '+') czynnik | liczba | 'sum' | zmienna | '(' wyrażenie ')'
+//! ```
+//!
+//! Liczby mogą mieć część dziesiętną zapisaną po kropce (`12.50`); obliczenia są dokładne
+//! (typ [`Decimal`]), a każdy wynik pośredni jest sprawdzany pod kątem przepełnienia.
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::decimal::{Decimal, NumberMode};
+
+/// Dane potrzebne do wyliczenia wyrażenia w trakcie sesji.
+#[derive(Debug, Clone, Copy)]
+pub struct Context<'a> {
+    /// Bieżąca suma sesji, dostępna w wyrażeniu jako `sum`.
+    pub total: Decimal,
+    /// Dopuszczalny zakres wartości.
+    pub mode: NumberMode,
+    /// Nazwane zmienne (rejestry) ustawione w sesji, np. `a = 5`.
+    pub variables: &'a BTreeMap<String, Decimal>,
+}
+
+/// Sprawdza, czy tekst może być nazwą zmiennej: litera lub `_`, potem litery, cyfry lub `_`.
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_alphabetic() || first == '_' => {
+            chars.all(|c| c.is_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+/// Błąd wyrażenia wraz z pozycją (numer znaku liczony od 1), w której wystąpił.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExprError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl ExprError {
+    fn new(position: usize, message: impl Into<String>) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "pozycja {}: {}", self.position, self.message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Number(Decimal),
+    Name(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    LeftParen,
+    RightParen,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::Number(value) => format!("liczba {value}"),
+            Token::Name(name) => format!("nazwa '{name}'"),
+            Token::Plus => "'+'".to_string(),
+            Token::Minus => "'-'".to_string(),
+            Token::Star => "'*'".to_string(),
+            Token::Slash => "'/'".to_string(),
+            Token::Percent => "'%'".to_string(),
+            Token::LeftParen => "'('".to_string(),
+            Token::RightParen => "')'".to_string(),
+        }
+    }
+}
+
+/// Dzieli wyrażenie na tokeny, zapamiętując pozycję początku każdego z nich.
+fn tokenize(input: &str) -> Result<Vec<(usize, Token)>, ExprError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let ch = chars[index];
+        let position = index + 1;
+
+        if ch.is_whitespace() {
+            index += 1;
+            continue;
+        }
+
+        if ch.is_ascii_digit() {
+            let start = index;
+            while index < chars.len() && (chars[index].is_ascii_digit() || chars[index] == '.') {
+                index += 1;
+            }
+            let literal: String = chars[start..index].iter().collect();
+            let value = literal
+                .parse::<Decimal>()
+                .map_err(|_| ExprError::new(position, format!("niepoprawna liczba: {literal}")))?;
+            tokens.push((position, Token::Number(value)));
+            continue;
+        }
+
+        if ch.is_alphabetic() || ch == '_' {
+            let start = index;
+            while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+                index += 1;
+            }
+            tokens.push((position, Token::Name(chars[start..index].iter().collect())));
+            continue;
+        }
+
+        let token = match ch {
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '%' => Token::Percent,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            other => {
+                return Err(ExprError::new(
+                    position,
+                    format!("nieoczekiwany znak '{other}'"),
+                ))
+            }
+        };
+        tokens.push((position, token));
+        index += 1;
+    }
+
+    Ok(tokens)
+}
+
+/// Parser zstępujący, który od razu wylicza wartość wyrażenia.
+struct Parser<'a> {
+    tokens: Vec<(usize, Token)>,
+    next: usize,
+    end: usize,
+    context: Context<'a>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.next).map(|(_, token)| token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens
+            .get(self.next)
+            .map(|(position, _)| *position)
+            .unwrap_or(self.end)
+    }
+
+    fn advance(&mut self) -> Option<(usize, Token)> {
+        let token = self.tokens.get(self.next).cloned();
+        self.next += 1;
+        token
+    }
+
+    /// Sprawdza, czy wynik operacji istnieje i mieści się w zakresie trybu.
+    fn checked(&self, value: Option<Decimal>, position: usize) -> Result<Decimal, ExprError> {
+        value
+            .and_then(|value| self.context.mode.check(value))
+            .ok_or_else(|| ExprError::new(position, "przepełnienie"))
+    }
+
+    fn expression(&mut self) -> Result<Decimal, ExprError> {
+        let mut value = self.term()?;
+        while let Some(token @ (Token::Plus | Token::Minus)) = self.peek().cloned() {
+            let position = self.position();
+            self.advance();
+            let rhs = self.term()?;
+            let result = match token {
+                Token::Plus => value.checked_add(rhs),
+                _ => value.checked_sub(rhs),
+            };
+            value = self.checked(result, position)?;
+        }
+        Ok(value)
+    }
+
+    fn term(&mut self) -> Result<Decimal, ExprError> {
+        let mut value = self.factor()?;
+        while let Some(token @ (Token::Star | Token::Slash | Token::Percent)) = self.peek().cloned()
+        {
+            let position = self.position();
+            self.advance();
+            let rhs = self.factor()?;
+            if rhs.is_zero() && token != Token::Star {
+                return Err(ExprError::new(position, "dzielenie przez zero"));
+            }
+            let result = match token {
+                Token::Star => value.checked_mul(rhs),
+                Token::Slash => value.checked_div(rhs),
+                _ => value.checked_rem(rhs),
+            };
+            value = self.checked(result, position)?;
+        }
+        Ok(value)
+    }
+
+    fn factor(&mut self) -> Result<Decimal, ExprError> {
+        let position = self.position();
+        match self.advance() {
+            Some((_, Token::Minus)) => {
+                let value = self.factor()?;
+                self.checked(value.checked_neg(), position)
+            }
+            Some((_, Token::Plus)) => self.factor(),
+            Some((_, Token::Number(value))) => {
+                self.context.mode.check(value).ok_or_else(|| {
+                    ExprError::new(position, format!("liczba poza zakresem: {value}"))
+                })
+            }
+            Some((_, Token::Name(name))) if name == "sum" => Ok(self.context.total),
+            Some((_, Token::Name(name))) => match self.context.variables.get(&name) {
+                Some(value) => Ok(*value),
+                None => Err(ExprError::new(position, format!("nieznana nazwa '{name}'"))),
+            },
+            Some((_, Token::LeftParen)) => {
+                let value = self.expression()?;
+                match self.advance() {
+                    Some((_, Token::RightParen)) => Ok(value),
+                    Some((position, token)) => Err(ExprError::new(
+                        position,
+                        format!("oczekiwano ')', znaleziono {}", token.describe()),
+                    )),
+                    None => Err(ExprError::new(self.end, "brak nawiasu zamykającego")),
+                }
+            }
+            Some((_, token)) => Err(ExprError::new(
+                position,
+                format!("oczekiwano liczby, znaleziono {}", token.describe()),
+            )),
+            None => Err(ExprError::new(position, "niepełne wyrażenie")),
+        }
+    }
+}
+
+/// Wylicza wartość wyrażenia. Nazwa `sum` oznacza bieżącą sumę sesji (`context.total`).
+/// Dzielenie dwóch liczb całkowitych jest całkowitoliczbowe (jak `/` dla `i32`);
+/// zasady dla liczb dziesiętnych opisuje [`Decimal::checked_div`].
+pub fn evaluate(input: &str, context: Context<'_>) -> Result<Decimal, ExprError> {
+    let tokens = tokenize(input)?;
+    let end = input.chars().count() + 1;
+    let mut parser = Parser {
+        tokens,
+        next: 0,
+        end,
+        context,
+    };
+
+    let value = parser.expression()?;
+    match parser.advance() {
+        None => Ok(value),
+        Some((position, token)) => Err(ExprError::new(
+            position,
+            format!("nieoczekiwany element: {}", token.describe()),
+        )),
+    }
+}
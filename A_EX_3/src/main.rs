@@ -1,48 +1,511 @@
-use std::io;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+
+pub mod decimal;
+pub mod expr;
+
+use decimal::{Decimal, NumberMode};
 
 /// Interpretuje jedną linię wejścia.
 /// - `Ok(Some(value))` oznacza liczbę całkowitą, którą trzeba dodać do sumy.
 /// - `Ok(None)` oznacza komendę zakończenia (`koniec`).
 /// - `Err(message)` zawiera komunikat, który należy wypisać na stdout.
 pub fn parse_line(line: &str) -> Result<Option<i32>, String> {
-    todo!()
+    let trimmed = line.trim();
+
+    if trimmed.is_empty() {
+        return Err("Wpisz liczbę lub 'koniec'.".to_string());
+    }
+    if trimmed == "koniec" {
+        return Ok(None);
+    }
+
+    match trimmed.parse::<i32>() {
+        Ok(value) => Ok(Some(value)),
+        Err(_) => Err(format!("Niepoprawna liczba: {trimmed}")),
+    }
+}
+
+/// Wariant `parse_line` używany przez sesję: zamiast samej liczby linia może być wyrażeniem
+/// z `+ - * / %`, nawiasami i minusem unarnym, a nazwa `sum` oznacza bieżącą sumę z `context`
+/// (np. `sum * 2`). Liczby mogą mieć część dziesiętną (`12.50`).
+pub fn parse_line_with(line: &str, context: expr::Context<'_>) -> Result<Option<Decimal>, String> {
+    let trimmed = line.trim();
+
+    if trimmed.is_empty() {
+        return Err("Wpisz liczbę lub 'koniec'.".to_string());
+    }
+    if trimmed == "koniec" {
+        return Ok(None);
+    }
+
+    match expr::evaluate(trimmed, context) {
+        Ok(value) => Ok(Some(value)),
+        Err(err) => Err(format!("Niepoprawne wyrażenie: {trimmed} ({err})")),
+    }
+}
+
+/// Komenda sesji rozpoznana w jednej linii wejścia.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Dodaj wartość wyrażenia do sumy.
+    Add(Decimal),
+    /// `nazwa = wyrażenie` lub `nazwa += wyrażenie` – ustaw zmienną (nie zmienia sumy).
+    Assign(String, Decimal),
+    /// `zmienne` – wypisz wszystkie zmienne.
+    Variables,
+    /// `zapisz <plik>` – zapisz transkrypt sesji do pliku.
+    Save(String),
+    /// `wczytaj <plik>` – odtwórz zapisany transkrypt w bieżącej sesji.
+    Load(String),
+    /// `koniec` – zakończ sesję.
+    Quit,
+    /// `cofnij` – usuń ostatni wpis.
+    Undo,
+    /// `reset` – wyczyść historię i wyzeruj sumę.
+    Reset,
+    /// `historia` – wypisz wszystkie wpisy.
+    History,
+    /// `srednia` – średnia wpisów.
+    Mean,
+    /// `min` – najmniejszy wpis.
+    Min,
+    /// `max` – największy wpis.
+    Max,
+    /// `pomoc` – lista komend.
+    Help,
+}
+
+/// Rozpoznaje przypisanie `nazwa = wyrażenie` (także `+=`, `-=`, `*=`, `/=`, `%=`)
+/// i wylicza nową wartość zmiennej. Zwraca `None`, gdy linia nie jest przypisaniem.
+fn parse_assignment(trimmed: &str, context: expr::Context<'_>) -> Option<Result<Command, String>> {
+    let (target, rhs) = trimmed.split_once('=')?;
+    let target = target.trim_end();
+    let (name, operator) = match target.chars().last()? {
+        op @ ('+' | '-' | '*' | '/' | '%') => (target[..target.len() - 1].trim(), Some(op)),
+        _ => (target.trim(), None),
+    };
+    if !expr::is_identifier(name) {
+        return None;
+    }
+    if name == "sum" {
+        return Some(Err("Nazwa 'sum' jest zarezerwowana dla sumy.".to_string()));
+    }
+
+    // Pozycje błędów w prawej stronie liczymy od początku całej linii.
+    let offset = trimmed.chars().count() - rhs.chars().count();
+    let value = match expr::evaluate(rhs, context) {
+        Ok(value) => value,
+        Err(mut err) => {
+            err.position += offset;
+            return Some(Err(format!("Niepoprawne wyrażenie: {trimmed} ({err})")));
+        }
+    };
+
+    let Some(op) = operator else {
+        return Some(Ok(Command::Assign(name.to_string(), value)));
+    };
+    let Some(&current) = context.variables.get(name) else {
+        return Some(Err(format!("Nieznana zmienna: {name}")));
+    };
+    if value.is_zero() && matches!(op, '/' | '%') {
+        return Some(Err(format!(
+            "Niepoprawne wyrażenie: {trimmed} (dzielenie przez zero)"
+        )));
+    }
+    let result = match op {
+        '+' => current.checked_add(value),
+        '-' => current.checked_sub(value),
+        '*' => current.checked_mul(value),
+        '/' => current.checked_div(value),
+        _ => current.checked_rem(value),
+    };
+    Some(
+        result
+            .and_then(|result| context.mode.check(result))
+            .map(|result| Command::Assign(name.to_string(), result))
+            .ok_or_else(|| format!("Przepełnienie: nie zmieniono zmiennej {name}.")),
+    )
+}
+
+/// Rozpoznaje komendę sesji. Linie, które nie są nazwą komendy ani przypisaniem,
+/// trafiają do `parse_line_with`.
+pub fn parse_command(line: &str, context: expr::Context<'_>) -> Result<Command, String> {
+    let trimmed = line.trim();
+    if let Some(path) = trimmed.strip_prefix("zapisz ") {
+        return Ok(Command::Save(path.trim().to_string()));
+    }
+    if let Some(path) = trimmed.strip_prefix("wczytaj ") {
+        return Ok(Command::Load(path.trim().to_string()));
+    }
+    if let Some(assignment) = parse_assignment(trimmed, context) {
+        return assignment;
+    }
+
+    let command = match trimmed {
+        "zmienne" => Command::Variables,
+        "cofnij" => Command::Undo,
+        "reset" => Command::Reset,
+        "historia" => Command::History,
+        "srednia" => Command::Mean,
+        "min" => Command::Min,
+        "max" => Command::Max,
+        "pomoc" => Command::Help,
+        _ => match parse_line_with(line, context)? {
+            Some(value) => Command::Add(value),
+            None => Command::Quit,
+        },
+    };
+    Ok(command)
+}
+
+/// Jedna obsłużona linia transkryptu wraz z komunikatami, które wypisał program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TranscriptEntry {
+    line: String,
+    messages: Vec<String>,
+}
+
+/// Stan sesji sumatora: pełna historia wpisów, ich bieżąca suma, zmienne i transkrypt.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Session {
+    entries: Vec<Decimal>,
+    total: Decimal,
+    mode: NumberMode,
+    variables: BTreeMap<String, Decimal>,
+    transcript: Vec<TranscriptEntry>,
+    replaying: bool,
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sesja z wybranym zakresem wartości (np. `NumberMode::Wide`).
+    pub fn with_mode(mode: NumberMode) -> Self {
+        Self {
+            mode,
+            ..Self::default()
+        }
+    }
+
+    /// Bieżąca suma wszystkich wpisów.
+    pub fn total(&self) -> Decimal {
+        self.total
+    }
+
+    /// Wpisy w kolejności dodawania.
+    pub fn entries(&self) -> &[Decimal] {
+        &self.entries
+    }
+
+    /// Zmienne ustawione w sesji.
+    pub fn variables(&self) -> &BTreeMap<String, Decimal> {
+        &self.variables
+    }
+
+    /// Transkrypt w formacie pliku zapisywanego przez `zapisz`: każda linia wejścia,
+    /// a pod nią komunikaty programu jako komentarze zaczynające się od `# `.
+    pub fn transcript(&self) -> Vec<String> {
+        let mut lines = vec!["# Transkrypt sesji sumatora".to_string()];
+        for entry in &self.transcript {
+            lines.push(entry.line.clone());
+            lines.extend(entry.messages.iter().map(|message| format!("# {message}")));
+        }
+        lines
+    }
+
+    fn context(&self) -> expr::Context<'_> {
+        expr::Context {
+            total: self.total,
+            mode: self.mode,
+            variables: &self.variables,
+        }
+    }
+
+    /// Sprawdza wynik działania na sumie; `None` oznacza przepełnienie.
+    fn checked_total(&self, value: Option<Decimal>) -> Option<Decimal> {
+        value.and_then(|value| self.mode.check(value))
+    }
+
+    /// Obsługuje jedną linię wejścia i zwraca komunikaty do wypisania.
+    /// Drugi element krotki to `true`, gdy sesja została zakończona komendą `koniec`.
+    /// Linie zaczynające się od `#` są komentarzami i nie dają żadnego komunikatu.
+    pub fn handle_line(&mut self, line: &str) -> (Vec<String>, bool) {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            return (Vec::new(), false);
+        }
+
+        let command = match parse_command(line, self.context()) {
+            Ok(command) => command,
+            Err(message) => {
+                if !trimmed.is_empty() {
+                    self.record(trimmed, std::slice::from_ref(&message));
+                }
+                return (vec![message], false);
+            }
+        };
+
+        match command {
+            Command::Quit => (vec![format!("Zamykam program. Suma: {}", self.total)], true),
+            Command::Save(path) => (vec![self.save(&path)], false),
+            Command::Load(path) => (self.load(&path), false),
+            command => {
+                let messages = self.execute(command);
+                self.record(trimmed, &messages);
+                (messages, false)
+            }
+        }
+    }
+
+    fn record(&mut self, line: &str, messages: &[String]) {
+        self.transcript.push(TranscriptEntry {
+            line: line.to_string(),
+            messages: messages.to_vec(),
+        });
+    }
+
+    /// Zapisuje transkrypt do pliku i zwraca komunikat o wyniku.
+    fn save(&self, path: &str) -> String {
+        let mut content = self.transcript().join("\n");
+        content.push('\n');
+        match fs::write(path, content) {
+            Ok(()) => format!("Zapisano {} linii do {}.", self.transcript.len(), path),
+            Err(err) => format!("Nie udało się zapisać pliku {path}: {err}"),
+        }
+    }
+
+    /// Odtwarza transkrypt z pliku w bieżącej sesji; `koniec` w pliku kończy tylko odtwarzanie.
+    fn load(&mut self, path: &str) -> Vec<String> {
+        if self.replaying {
+            return vec!["Nie można wczytać pliku podczas odtwarzania innego.".to_string()];
+        }
+        let lines = match load_transcript(path) {
+            Ok(lines) => lines,
+            Err(message) => return vec![message],
+        };
+
+        self.replaying = true;
+        let mut messages = Vec::new();
+        for line in lines {
+            let (replies, finished) = self.handle_line(&line);
+            messages.extend(
+                replies
+                    .into_iter()
+                    .filter(|reply| !reply.starts_with("Zamykam")),
+            );
+            if finished {
+                break;
+            }
+        }
+        self.replaying = false;
+        messages.push(format!("Wczytano {}. Aktualna suma: {}", path, self.total));
+        messages
+    }
+
+    /// Wykonuje komendę zmieniającą lub opisującą stan sesji.
+    fn execute(&mut self, command: Command) -> Vec<String> {
+        match command {
+            Command::Add(value) => match self.checked_total(self.total.checked_add(value)) {
+                Some(total) => {
+                    self.entries.push(value);
+                    self.total = total;
+                    vec![format!("Aktualna suma: {}", self.total)]
+                }
+                None => vec![format!(
+                    "Przepełnienie: nie dodano {}. Aktualna suma: {}",
+                    value, self.total
+                )],
+            },
+            Command::Assign(name, value) => {
+                let message = format!("{} = {}", name, value);
+                self.variables.insert(name, value);
+                vec![message]
+            }
+            Command::Variables if self.variables.is_empty() => {
+                vec!["Brak zmiennych.".to_string()]
+            }
+            Command::Variables => self
+                .variables
+                .iter()
+                .map(|(name, value)| format!("{} = {}", name, value))
+                .collect(),
+            Command::Undo => match self.entries.last().copied() {
+                Some(value) => match self.checked_total(self.total.checked_sub(value)) {
+                    Some(total) => {
+                        self.entries.pop();
+                        self.total = total;
+                        vec![format!("Cofnięto {}. Aktualna suma: {}", value, self.total)]
+                    }
+                    None => vec![format!("Przepełnienie: nie cofnięto {}.", value)],
+                },
+                None => vec!["Brak wpisów do cofnięcia.".to_string()],
+            },
+            Command::Reset => {
+                self.entries.clear();
+                self.total = Decimal::ZERO;
+                vec!["Wyzerowano sumę. Aktualna suma: 0".to_string()]
+            }
+            Command::History if self.entries.is_empty() => vec!["Historia jest pusta.".to_string()],
+            Command::History => self
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(index, value)| format!("{}. {}", index + 1, value))
+                .collect(),
+            Command::Mean | Command::Min | Command::Max if self.entries.is_empty() => {
+                vec!["Brak wpisów.".to_string()]
+            }
+            Command::Mean => {
+                let count = Decimal::from_int(self.entries.len() as i128);
+                let scale = self.total.scale().max(2);
+                match self.total.div_to_scale(count, scale) {
+                    Some(mean) => vec![format!("Średnia: {}", mean)],
+                    None => vec!["Przepełnienie: nie można policzyć średniej.".to_string()],
+                }
+            }
+            Command::Min => vec![format!(
+                "Minimum: {}",
+                self.entries.iter().min().copied().unwrap_or_default()
+            )],
+            Command::Max => vec![format!(
+                "Maksimum: {}",
+                self.entries.iter().max().copied().unwrap_or_default()
+            )],
+            Command::Help => help_lines(),
+            Command::Quit | Command::Save(_) | Command::Load(_) => Vec::new(),
+        }
+    }
+}
+
+/// Wczytuje zapisany transkrypt jako linie gotowe do przekazania do `run_session`.
+pub fn load_transcript(path: &str) -> Result<Vec<String>, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(content.lines().map(str::to_string).collect()),
+        Err(err) => Err(format!("Nie udało się odczytać pliku {path}: {err}")),
+    }
+}
+
+/// Opis dostępnych komend wypisywany przez `pomoc`.
+pub fn help_lines() -> Vec<String> {
+    let commands = [
+        (
+            "<wyrażenie>",
+            "dodaj wartość do sumy (np. 5, (2 + 3) * 4, sum * 2)",
+        ),
+        ("cofnij", "usuń ostatni wpis"),
+        ("reset", "wyczyść historię i wyzeruj sumę"),
+        ("historia", "wypisz wszystkie wpisy"),
+        ("srednia", "średnia wpisów"),
+        ("min, max", "najmniejszy i największy wpis"),
+        ("a = 5", "ustaw zmienną (także +=, -=, *=, /=, %=)"),
+        ("zmienne", "wypisz wszystkie zmienne"),
+        ("zapisz <plik>", "zapisz transkrypt sesji"),
+        ("wczytaj <plik>", "odtwórz zapisany transkrypt"),
+        ("pomoc", "ta lista"),
+        ("koniec", "zakończ program"),
+    ];
+
+    let mut lines = vec!["Dostępne komendy:".to_string()];
+    lines.extend(
+        commands
+            .iter()
+            .map(|(command, description)| format!("  {command:<15} {description}")),
+    );
+    lines
 }
 
 /// Przetwarza sekwencję linii tekstu i zwraca komunikaty do wypisania przez program.
-/// Powinna używać `parse_line`, aktualizować sumę liczb i zakończyć działanie po `Ok(None)`.
+/// Każdą linię przekazuje do [`Session::handle_line`], która obsługuje komendy (`cofnij`,
+/// `historia`, `zmienne`…), przypisania zmiennych i wyrażenia liczone przez [`parse_line_with`]
+/// w arytmetyce dziesiętnej, dodając ich wartości do sumy; sesja kończy się po `koniec`.
 pub fn run_session<I>(lines: I) -> Vec<String>
 where
     I: IntoIterator,
     I::Item: AsRef<str>,
 {
-    todo!()
+    run_session_with(lines, NumberMode::Standard)
 }
 
-pub fn main() {
-    let stdin = io::stdin();
+/// Wariant `run_session` z wybranym zakresem wartości.
+pub fn run_session_with<I>(lines: I, mode: NumberMode) -> Vec<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut session = Session::with_mode(mode);
+    let mut messages = Vec::new();
+
+    for line in lines {
+        let (replies, finished) = session.handle_line(line.as_ref());
+        messages.extend(replies);
+        if finished {
+            break;
+        }
+    }
+
+    messages
+}
+
+/// Wybiera zakres wartości na podstawie argumentu programu (`--szeroki`).
+pub fn parse_mode(arg: Option<&str>) -> Result<NumberMode, String> {
+    match arg {
+        None => Ok(NumberMode::Standard),
+        Some("--szeroki") => Ok(NumberMode::Wide),
+        Some(other) => Err(format!("Nieznany argument: {}", other)),
+    }
+}
+
+/// Prowadzi interaktywną sesję: czyta kolejne linie z `input` i od razu wypisuje
+/// odpowiedzi do `output`, aż do komendy `koniec` lub końca wejścia.
+pub fn run_interactive<R, W>(mut input: R, output: &mut W, mode: NumberMode) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
     let mut buffer = String::new();
-    let mut total = 0;
+    let mut session = Session::with_mode(mode);
 
     loop {
         buffer.clear();
 
-        if stdin.read_line(&mut buffer).is_err() {
-            println!("Wpisz liczbę lub 'koniec'.");
-            continue;
+        match input.read_line(&mut buffer) {
+            // Koniec strumienia wejścia traktujemy jak komendę `koniec`.
+            Ok(0) => buffer.push_str("koniec"),
+            Ok(_) => {}
+            Err(_) => {
+                writeln!(output, "Wpisz liczbę lub 'koniec'.")?;
+                continue;
+            }
         }
 
-        match parse_line(buffer.as_str()) {
-            Ok(Some(value)) => {
-                total += value;
-                println!("Aktualna suma: {}", total);
-            }
-            Ok(None) => {
-                println!("Zamykam program. Suma: {}", total);
-                break;
-            }
-            Err(message) => {
-                println!("{}", message);
-            }
+        let (messages, finished) = session.handle_line(buffer.as_str());
+        for message in messages {
+            writeln!(output, "{}", message)?;
+        }
+        output.flush()?;
+        if finished {
+            return Ok(());
         }
     }
 }
+
+pub fn main() {
+    let mode = match parse_mode(env::args().nth(1).as_deref()) {
+        Ok(mode) => mode,
+        Err(message) => {
+            println!("{}", message);
+            return;
+        }
+    };
+
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    if let Err(error) = run_interactive(stdin.lock(), &mut stdout.lock(), mode) {
+        eprintln!("Błąd wejścia/wyjścia: {}", error);
+    }
+}
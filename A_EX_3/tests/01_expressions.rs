@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+
+use a_ex_3::decimal::{Decimal, NumberMode};
+use a_ex_3::expr::{evaluate, is_identifier, Context};
+use a_ex_3::run_session;
+
+fn eval(input: &str) -> Result<String, String> {
+    let variables = BTreeMap::from([("a".to_string(), Decimal::from(5))]);
+    let context = Context { total: Decimal::from(10), mode: NumberMode::Standard, variables: &variables };
+    evaluate(input, context).map(|value| value.to_string()).map_err(|err| err.to_string())
+}
+
+#[test]
+fn precedence_parentheses_and_unary_minus() {
+    assert_eq!(eval("2 + 3 * 4"), Ok("14".to_string()));
+    assert_eq!(eval("(2 + 3) * -4"), Ok("-20".to_string()));
+    assert_eq!(eval("--3"), Ok("3".to_string()));
+    assert_eq!(eval("7 / 2 + 7 % 3"), Ok("4".to_string()));
+    assert_eq!(eval("-7 / 2"), Ok("-3".to_string()));
+    assert_eq!(eval("sum * 2 - a"), Ok("15".to_string()));
+}
+
+#[test]
+fn errors_point_at_the_character() {
+    assert_eq!(eval("2 + * 3"), Err("pozycja 5: oczekiwano liczby, znaleziono '*'".to_string()));
+    assert_eq!(eval("1 / 0"), Err("pozycja 3: dzielenie przez zero".to_string()));
+    assert_eq!(eval("(1 + 2"), Err("pozycja 7: brak nawiasu zamykającego".to_string()));
+    assert_eq!(eval("1 2"), Err("pozycja 3: nieoczekiwany element: liczba 2".to_string()));
+    assert_eq!(eval("2 $ 3"), Err("pozycja 3: nieoczekiwany znak '$'".to_string()));
+    assert_eq!(eval("x + 1"), Err("pozycja 1: nieznana nazwa 'x'".to_string()));
+    assert_eq!(eval("1 +"), Err("pozycja 4: niepełne wyrażenie".to_string()));
+    assert_eq!(eval("65536 * 65536"), Err("pozycja 7: przepełnienie".to_string()));
+}
+
+#[test]
+fn identifiers() {
+    assert!(is_identifier("_tmp1"));
+    assert!(is_identifier("żółw"));
+    assert!(!is_identifier("1a"));
+    assert!(!is_identifier(""));
+}
+
+#[test]
+fn expressions_are_added_to_the_total() {
+    let messages = run_session(["(2 + 3) * -4", "2 + * 3", "sum * 2", "koniec"]);
+    assert_eq!(messages, vec![
+        "Aktualna suma: -20",
+        "Niepoprawne wyrażenie: 2 + * 3 (pozycja 5: oczekiwano liczby, znaleziono '*')",
+        "Aktualna suma: -60",
+        "Zamykam program. Suma: -60",
+    ]);
+}
@@ -0,0 +1,56 @@
+use a_ex_3::decimal::{Decimal, NumberMode};
+use a_ex_3::expr::Context;
+use a_ex_3::{help_lines, parse_command, run_interactive, run_session, Command, Session};
+
+#[test]
+fn history_statistics_and_undo() {
+    let messages = run_session([
+        "5", "-3", "historia", "srednia", "min", "max", "cofnij", "cofnij", "cofnij", "koniec",
+    ]);
+    assert_eq!(messages, vec![
+        "Aktualna suma: 5",
+        "Aktualna suma: 2",
+        "1. 5",
+        "2. -3",
+        "Średnia: 1.00",
+        "Minimum: -3",
+        "Maksimum: 5",
+        "Cofnięto -3. Aktualna suma: 5",
+        "Cofnięto 5. Aktualna suma: 0",
+        "Brak wpisów do cofnięcia.",
+        "Zamykam program. Suma: 0",
+    ]);
+}
+
+#[test]
+fn reset_clears_history() {
+    let mut session = Session::new();
+    session.handle_line("4");
+    session.handle_line("6");
+    assert_eq!(session.handle_line("reset"), (vec!["Wyzerowano sumę. Aktualna suma: 0".to_string()], false));
+    assert_eq!(session.total(), Decimal::ZERO);
+    assert!(session.entries().is_empty());
+    assert_eq!(session.handle_line("historia").0, vec!["Historia jest pusta."]);
+    assert_eq!(session.handle_line("srednia").0, vec!["Brak wpisów."]);
+}
+
+#[test]
+fn commands_are_recognized_before_expressions() {
+    let variables = Default::default();
+    let context = Context { total: Decimal::ZERO, mode: NumberMode::Standard, variables: &variables };
+    assert_eq!(parse_command(" cofnij ", context), Ok(Command::Undo));
+    assert_eq!(parse_command("koniec", context), Ok(Command::Quit));
+    assert_eq!(parse_command("1 + 1", context), Ok(Command::Add(Decimal::from(2))));
+    assert_eq!(parse_command("", context), Err("Wpisz liczbę lub 'koniec'.".to_string()));
+    assert_eq!(help_lines()[0], "Dostępne komendy:");
+    assert_eq!(run_session(["pomoc"]), help_lines());
+}
+
+#[test]
+fn interactive_loop_matches_run_session_and_stops_at_end_of_input() {
+    let mut output = Vec::new();
+    run_interactive("5\nhistoria\n".as_bytes(), &mut output, NumberMode::Standard).unwrap();
+    let printed = String::from_utf8(output).unwrap();
+    assert_eq!(printed, "Aktualna suma: 5\n1. 5\nZamykam program. Suma: 5\n");
+    assert_eq!(printed.lines().collect::<Vec<_>>(), run_session(["5", "historia", "koniec"]));
+}
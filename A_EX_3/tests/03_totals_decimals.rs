@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+use a_ex_3::decimal::{Decimal, NumberMode, MAX_SCALE};
+use a_ex_3::expr::Context;
+use a_ex_3::{parse_line, parse_line_with, parse_mode, run_session, run_session_with};
+
+fn dec(text: &str) -> Decimal {
+    text.parse().expect("valid decimal")
+}
+
+#[test]
+fn parse_line_keeps_the_original_contract() {
+    assert_eq!(parse_line("  42 \n"), Ok(Some(42)));
+    assert_eq!(parse_line("-7"), Ok(Some(-7)));
+    assert_eq!(parse_line("koniec"), Ok(None));
+    assert_eq!(parse_line("   "), Err("Wpisz liczbę lub 'koniec'.".to_string()));
+    assert_eq!(parse_line(" 2 + 2 "), Err("Niepoprawna liczba: 2 + 2".to_string()));
+    assert_eq!(parse_line("12.5"), Err("Niepoprawna liczba: 12.5".to_string()));
+    assert_eq!(parse_line("2147483648"), Err("Niepoprawna liczba: 2147483648".to_string()));
+}
+
+#[test]
+fn parse_line_with_evaluates_decimals_against_context() {
+    let variables = BTreeMap::new();
+    let context = Context { total: Decimal::from(10), mode: NumberMode::Standard, variables: &variables };
+    assert_eq!(parse_line_with("sum * 2", context), Ok(Some(Decimal::from(20))));
+    assert_eq!(parse_line_with("0.1 + 0.2", context), Ok(Some(dec("0.3"))));
+    assert_eq!(parse_line_with("koniec", context), Ok(None));
+    assert_eq!(
+        parse_line_with("2147483647 + 1", context),
+        Err("Niepoprawne wyrażenie: 2147483647 + 1 (pozycja 12: przepełnienie)".to_string())
+    );
+}
+
+#[test]
+fn overflowing_entries_are_rejected_without_changing_the_total() {
+    let messages = run_session(["2147483647", "1", "-1", "koniec"]);
+    assert_eq!(messages, vec![
+        "Aktualna suma: 2147483647",
+        "Przepełnienie: nie dodano 1. Aktualna suma: 2147483647",
+        "Aktualna suma: 2147483646",
+        "Zamykam program. Suma: 2147483646",
+    ]);
+
+    let messages = run_session_with(["2147483647", "1", "koniec"], NumberMode::Wide);
+    assert_eq!(messages[2], "Zamykam program. Suma: 2147483648");
+    assert_eq!(parse_mode(Some("--szeroki")), Ok(NumberMode::Wide));
+    assert_eq!(parse_mode(Some("--inny")), Err("Nieznany argument: --inny".to_string()));
+}
+
+#[test]
+fn decimal_arithmetic_is_exact() {
+    assert_eq!(dec("12.50").checked_add(Decimal::from(1)).unwrap().to_string(), "13.50");
+    assert_eq!(dec("1.0"), Decimal::from(1));
+    assert_eq!(dec("-0.5").to_string(), "-0.5");
+    assert_eq!(Decimal::from(7).checked_div(Decimal::from(2)), Some(Decimal::from(3)));
+    assert_eq!(dec("1").checked_div(dec("3.0")).unwrap().to_string(), "0.3333333333");
+    assert_eq!(Decimal::from(1).checked_div(Decimal::ZERO), None);
+    assert_eq!(dec("-7.5").checked_rem(Decimal::from(2)).unwrap(), dec("-1.5"));
+    assert_eq!(Decimal::from(10).div_to_scale(Decimal::from(4), 2).unwrap().to_string(), "2.50");
+}
+
+#[test]
+fn decimal_scale_is_limited() {
+    let longest = format!("0.{}1", "0".repeat(MAX_SCALE as usize - 1));
+    assert_eq!(dec(&longest).scale(), MAX_SCALE);
+    let too_long = format!("0.{}1", "0".repeat(MAX_SCALE as usize));
+    assert_eq!(too_long.parse::<Decimal>(), Err(format!("Za dużo cyfr po przecinku: {too_long}")));
+    assert_eq!(Decimal::new(1, MAX_SCALE + 1), None);
+
+    // Iloczyn o zbyt dużej skali jest zaokrąglany do `MAX_SCALE` cyfr.
+    let product = dec("0.000000001").checked_mul(dec("0.0000000015")).unwrap();
+    assert_eq!(product.scale(), MAX_SCALE);
+    assert_eq!(product.to_string(), "0.000000000000000002");
+
+    // Wyrównanie skal, które przepełniłoby mantysę, daje `None` zamiast paniki.
+    let huge = Decimal::new(i128::MAX / 5, 0).unwrap();
+    assert_eq!(huge.checked_add(dec("0.5")), None);
+    assert!(huge > dec("0.5"));
+    assert_eq!("1e5".parse::<Decimal>(), Err("Niepoprawna liczba: 1e5".to_string()));
+}
@@ -0,0 +1,66 @@
+use std::fs;
+
+use a_ex_3::decimal::Decimal;
+use a_ex_3::{load_transcript, run_session, Session};
+
+#[test]
+fn variables_are_assigned_and_used_in_expressions() {
+    let messages = run_session([
+        "a = 5", "a *= 2", "a + 1", "zmienne", "sum = 3", "b += 1", "a /= 0", "koniec",
+    ]);
+    assert_eq!(messages, vec![
+        "a = 5",
+        "a = 10",
+        "Aktualna suma: 11",
+        "a = 10",
+        "Nazwa 'sum' jest zarezerwowana dla sumy.",
+        "Nieznana zmienna: b",
+        "Niepoprawne wyrażenie: a /= 0 (dzielenie przez zero)",
+        "Zamykam program. Suma: 11",
+    ]);
+    assert_eq!(run_session(["zmienne"]), vec!["Brak zmiennych."]);
+    assert_eq!(
+        run_session(["a = 2147483647", "a += 1"])[1],
+        "Przepełnienie: nie zmieniono zmiennej a."
+    );
+}
+
+#[test]
+fn transcript_lists_lines_with_commented_replies() {
+    let mut session = Session::new();
+    session.handle_line("a = 5");
+    session.handle_line("a + 1");
+    session.handle_line("# komentarz");
+    assert_eq!(session.transcript(), vec![
+        "# Transkrypt sesji sumatora",
+        "a = 5",
+        "# a = 5",
+        "a + 1",
+        "# Aktualna suma: 6",
+    ]);
+    assert_eq!(session.variables().get("a"), Some(&Decimal::from(5)));
+}
+
+#[test]
+fn saved_session_can_be_replayed() {
+    let path = std::env::temp_dir().join(format!("a_ex_3_session_{}.txt", std::process::id()));
+    let path = path.display().to_string();
+
+    let mut session = Session::new();
+    session.handle_line("a = 5");
+    session.handle_line("a * 2");
+    let (saved, _) = session.handle_line(&format!("zapisz {path}"));
+    assert_eq!(saved, vec![format!("Zapisano 2 linii do {path}.")]);
+
+    let replayed = run_session(load_transcript(&path).unwrap());
+    assert_eq!(replayed, vec!["a = 5", "Aktualna suma: 10"]);
+
+    let mut loaded = Session::new();
+    let (messages, finished) = loaded.handle_line(&format!("wczytaj {path}"));
+    assert!(!finished);
+    assert_eq!(messages.last().unwrap(), &format!("Wczytano {path}. Aktualna suma: 10"));
+    assert_eq!(loaded.total(), Decimal::from(10));
+
+    fs::remove_file(&path).unwrap();
+    assert!(load_transcript(&path).unwrap_err().starts_with("Nie udało się odczytać pliku"));
+}
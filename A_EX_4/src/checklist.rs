@@ -0,0 +1,226 @@
+//! Odczyt wygenerowanej checklisty, odhaczanie pozycji i podsumowanie postępu.
+//!
+//! Format pliku jest taki sam jak wynik programu: linie `N. [ ] temat` albo `N. [x] temat`,
+//! a pod nimi linie `Podsumowanie: …` i `Postęp: …`, które przy odczycie są pomijane.
+//! Podtematy mają numerację hierarchiczną (`1.1. [ ] temat`) i są wcięte o dwie spacje
+//! na każdy poziom.
+
+/// Pojedyncza pozycja checklisty.
+///
+/// Lista pozycji jest płaska i uporządkowana jak w wyniku programu: po każdym temacie
+/// następują jego podtematy, czyli kolejne pozycje o większym `depth`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecklistItem {
+    pub topic: String,
+    pub done: bool,
+    /// Poziom zagnieżdżenia; `0` oznacza temat główny.
+    pub depth: usize,
+}
+
+impl ChecklistItem {
+    /// Tworzy nieodhaczoną pozycję na najwyższym poziomie.
+    pub fn new(topic: impl Into<String>) -> Self {
+        Self::nested(topic, 0)
+    }
+
+    /// Tworzy nieodhaczoną pozycję na podanym poziomie zagnieżdżenia.
+    pub fn nested(topic: impl Into<String>, depth: usize) -> Self {
+        Self {
+            topic: topic.into(),
+            done: false,
+            depth,
+        }
+    }
+}
+
+/// Początki linii dopisywanych pod checklistą, które nie są jej pozycjami.
+const SUMMARY_PREFIXES: [&str; 2] = ["Podsumowanie:", "Postęp:"];
+
+/// Parsuje linię `N. [ ] temat` lub `N.M. [x] temat` (wielkość `x` nie ma znaczenia).
+fn parse_item(line: &str) -> Option<ChecklistItem> {
+    let (number, rest) = line.split_once(". ")?;
+    let parts: Vec<&str> = number.split('.').collect();
+    if parts
+        .iter()
+        .any(|part| part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()))
+    {
+        return None;
+    }
+    let (done, topic) = if let Some(topic) = rest.strip_prefix("[ ]") {
+        (false, topic)
+    } else if let Some(topic) = rest
+        .strip_prefix("[x]")
+        .or_else(|| rest.strip_prefix("[X]"))
+    {
+        (true, topic)
+    } else {
+        return None;
+    };
+    let topic = topic.trim();
+    (!topic.is_empty()).then(|| ChecklistItem {
+        topic: topic.to_string(),
+        done,
+        depth: parts.len() - 1,
+    })
+}
+
+/// Wczytuje checklistę zapisaną przez program. Puste linie oraz linie podsumowania są pomijane,
+/// a numery pozycji nie muszą być kolejne (przy zapisie lista jest numerowana od nowa).
+/// Poziom pozycji wynika z liczby członów numeru i może wzrosnąć najwyżej o jeden.
+pub fn parse_checklist(text: &str) -> Result<Vec<ChecklistItem>, String> {
+    let mut items: Vec<ChecklistItem> = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || SUMMARY_PREFIXES.iter().any(|p| line.starts_with(p)) {
+            continue;
+        }
+        let max_depth = items.last().map_or(0, |item| item.depth + 1);
+        match parse_item(line) {
+            Some(item) if item.depth <= max_depth => items.push(item),
+            _ => return Err(format!("Niepoprawna linia {}: {line}", index + 1)),
+        }
+    }
+
+    if items.is_empty() {
+        return Err("Brak tematów do przećwiczenia.".to_string());
+    }
+    Ok(items)
+}
+
+/// Wyznacza numery hierarchiczne pozycji, np. `1`, `1.1`, `1.2`, `2` (bez końcowej kropki).
+pub fn item_numbers(items: &[ChecklistItem]) -> Vec<String> {
+    let mut counters: Vec<usize> = Vec::new();
+    items
+        .iter()
+        .map(|item| {
+            counters.truncate(item.depth + 1);
+            if counters.len() == item.depth + 1 {
+                counters[item.depth] += 1;
+            } else {
+                counters.resize(item.depth + 1, 1);
+            }
+            counters
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join(".")
+        })
+        .collect()
+}
+
+/// Wyznacza pełne ścieżki tematów, np. `Rust > Ownership`, jednoznacznie opisujące pozycje
+/// niezależnie od numeracji.
+pub fn item_paths(items: &[ChecklistItem]) -> Vec<String> {
+    let mut ancestors: Vec<&str> = Vec::new();
+    items
+        .iter()
+        .map(|item| {
+            ancestors.truncate(item.depth);
+            ancestors.push(&item.topic);
+            ancestors.join(" > ")
+        })
+        .collect()
+}
+
+/// Sprawdza, czy pozycja o indeksie `index` ma podtematy.
+pub fn is_parent(items: &[ChecklistItem], index: usize) -> bool {
+    items
+        .get(index + 1)
+        .is_some_and(|next| next.depth > items[index].depth)
+}
+
+/// Buduje numerowaną checklistę z zaznaczeniem ukończonych pozycji (`[x]`).
+/// Podtematy są wcięte i mają numerację hierarchiczną (`1.1.`, `1.2.`).
+pub fn format_items(items: &[ChecklistItem]) -> Vec<String> {
+    items
+        .iter()
+        .zip(item_numbers(items))
+        .map(|(item, number)| {
+            let mark = if item.done { 'x' } else { ' ' };
+            let indent = "  ".repeat(item.depth);
+            format!("{indent}{number}. [{mark}] {}", item.topic)
+        })
+        .collect()
+}
+
+/// Zmienia stan pozycji o podanym numerze (`3` albo `1.2`, końcowa kropka jest dozwolona)
+/// i zwraca jej nowy stan.
+pub fn toggle_item(items: &mut [ChecklistItem], number: &str) -> Result<bool, String> {
+    let wanted = number.trim().trim_end_matches('.');
+    let index = item_numbers(items)
+        .iter()
+        .position(|candidate| candidate == wanted)
+        .ok_or_else(|| format!("Brak pozycji numer {}.", number.trim()))?;
+    let item = &mut items[index];
+    item.done = !item.done;
+    Ok(item.done)
+}
+
+/// Liczby opisujące checklistę, wspólne dla wszystkich formatów wyniku.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChecklistStats {
+    /// Liczba wszystkich pozycji.
+    pub items: usize,
+    /// Pozycje, które mają podtematy.
+    pub parents: usize,
+    /// Pozycje bez podtematów.
+    pub leaves: usize,
+    /// Łączna liczba znaków w tematach z pominięciem białych znaków.
+    pub characters: usize,
+    /// Pozycje odhaczone jako ukończone.
+    pub done: usize,
+    /// Procent ukończonych pozycji, zaokrąglony w dół.
+    pub percent: usize,
+}
+
+/// Wylicza liczby podsumowania i postępu dla listy pozycji.
+pub fn checklist_stats(items: &[ChecklistItem]) -> ChecklistStats {
+    let parents = (0..items.len())
+        .filter(|&index| is_parent(items, index))
+        .count();
+    let characters = items
+        .iter()
+        .map(|item| item.topic.chars().filter(|c| !c.is_whitespace()).count())
+        .sum();
+    let done = items.iter().filter(|item| item.done).count();
+    let percent = if items.is_empty() {
+        0
+    } else {
+        done * 100 / items.len()
+    };
+
+    ChecklistStats {
+        items: items.len(),
+        parents,
+        leaves: items.len() - parents,
+        characters,
+        done,
+        percent,
+    }
+}
+
+/// Tworzy linię podsumowania. Dla płaskiej listy ma ona postać znaną z `build_summary`,
+/// a gdy są podtematy, osobno liczy tematy nadrzędne i końcowe (bez podtematów).
+pub fn summarize_items(items: &[ChecklistItem]) -> String {
+    let stats = checklist_stats(items);
+    if stats.parents == 0 {
+        format!(
+            "Podsumowanie: {} elementów, {} znaków (bez spacji)",
+            stats.items, stats.characters
+        )
+    } else {
+        format!(
+            "Podsumowanie: {} elementów ({} nadrzędnych, {} końcowych), {} znaków (bez spacji)",
+            stats.items, stats.parents, stats.leaves, stats.characters
+        )
+    }
+}
+
+/// Tworzy linię postępu, np. `Postęp: 3/7 ukończone, 42%` (procent zaokrąglony w dół).
+pub fn build_progress(items: &[ChecklistItem]) -> String {
+    let stats = checklist_stats(items);
+    format!(
+        "Postęp: {}/{} ukończone, {}%",
+        stats.done, stats.items, stats.percent
+    )
+}
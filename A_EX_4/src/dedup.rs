@@ -0,0 +1,114 @@
+//! Łączenie powtórzonych i prawie identycznych tematów.
+//!
+//! Dwa tematy na tym samym poziomie i pod tym samym rodzicem są uznawane za ten sam temat,
+//! jeśli po sprowadzeniu do małych liter i złączeniu białych znaków są równe albo ich
+//! odległość edycyjna (Levenshteina) nie przekracza progu. Dopasowanie przybliżone dotyczy
+//! tylko tematów dłuższych niż dwukrotność progu, żeby krótkie nazwy (`Go`, `C`) nie
+//! zlewały się ze sobą. Zostaje pierwsze wystąpienie, a podtematy duplikatów trafiają pod nie.
+use crate::checklist::ChecklistItem;
+use crate::outline;
+
+/// Domyślny próg odległości edycyjnej dla dopasowania przybliżonego.
+pub const DEFAULT_THRESHOLD: usize = 1;
+
+/// Informacja o temacie dołączonym do innego (kanonicznego) tematu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Merge {
+    /// Temat w postaci, w jakiej wystąpił na wejściu.
+    pub input: String,
+    /// Temat, który zostaje na checkliście.
+    pub canonical: String,
+}
+
+/// Postać tematu używana do porównań: małe litery i pojedyncze spacje.
+pub fn normalize_topic(topic: &str) -> String {
+    topic
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Odległość Levenshteina liczona po znakach Unicode.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, &left) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, &right) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left != right);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Sprawdza, czy dwa tematy należy połączyć przy podanym progu odległości.
+/// Próg `0` oznacza tylko dokładne dopasowanie bez względu na wielkość liter.
+pub fn is_duplicate(a: &str, b: &str, threshold: usize) -> bool {
+    let (a, b) = (normalize_topic(a), normalize_topic(b));
+    if a == b {
+        return true;
+    }
+    let shorter = a.chars().count().min(b.chars().count());
+    shorter > 2 * threshold && edit_distance(&a, &b) <= threshold
+}
+
+/// Łączy powtórzone tematy i zwraca oczyszczoną listę oraz raport połączeń
+/// w kolejności występowania duplikatów. Połączona pozycja jest ukończona,
+/// jeśli ukończona była którakolwiek z łączonych pozycji.
+pub fn deduplicate(items: &[ChecklistItem], threshold: usize) -> (Vec<ChecklistItem>, Vec<Merge>) {
+    let mut result: Vec<ChecklistItem> = Vec::new();
+    let mut merges = Vec::new();
+    // Indeksy w `result` odpowiadające przodkom bieżącej pozycji wejścia.
+    let mut ancestors: Vec<usize> = Vec::new();
+
+    for item in items {
+        ancestors.truncate(item.depth);
+        let parent = ancestors.last().copied();
+        let depth = parent.map_or(0, |index| result[index].depth + 1);
+        let start = parent.map_or(0, |index| index + 1);
+        let end = outline::subtree_end(&result, parent);
+
+        let existing = (start..end).find(|&index| {
+            result[index].depth == depth
+                && is_duplicate(&result[index].topic, &item.topic, threshold)
+        });
+        let index = match existing {
+            Some(index) => {
+                merges.push(Merge {
+                    input: item.topic.clone(),
+                    canonical: result[index].topic.clone(),
+                });
+                result[index].done |= item.done;
+                index
+            }
+            None => {
+                // Wstawiamy za poddrzewem rodzica; przodkowie leżą przed tym miejscem,
+                // więc ich indeksy w `ancestors` pozostają aktualne.
+                result.insert(
+                    end,
+                    ChecklistItem {
+                        depth,
+                        ..item.clone()
+                    },
+                );
+                end
+            }
+        };
+        ancestors.push(index);
+    }
+
+    (result, merges)
+}
+
+/// Formatuje raport połączeń jako linie `Połączono: traits → Traits`.
+pub fn format_merges(merges: &[Merge]) -> Vec<String> {
+    merges
+        .iter()
+        .map(|merge| format!("Połączono: {} → {}", merge.input, merge.canonical))
+        .collect()
+}
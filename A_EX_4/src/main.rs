@@ -1,39 +1,311 @@
-use std::io;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+
+pub mod checklist;
+pub mod dedup;
+pub mod outline;
+pub mod render;
+pub mod schedule;
+
+use checklist::ChecklistItem;
+use render::OutputFormat;
+use schedule::{Clock, FixedClock, SystemClock};
 
 /// Zbiera tematy z pojedynczej linii wejścia rozdzielonej przecinkami.
 /// Zwraca błąd `"Brak tematów do przećwiczenia."`, jeśli po oczyszczeniu nie ma żadnych wpisów.
 pub fn collect_topics(line: &str) -> Result<Vec<String>, String> {
-    todo!()
+    let topics: Vec<String> = line
+        .split(',')
+        .map(|chunk| chunk.trim())
+        .filter(|chunk| !chunk.is_empty())
+        .map(|chunk| chunk.to_string())
+        .collect();
+
+    if topics.is_empty() {
+        return Err("Brak tematów do przećwiczenia.".to_string());
+    }
+    Ok(topics)
 }
 
 /// Buduje numerowaną checklistę w formacie `"{}. [ ] {}"` dla każdego tematu.
+/// Tematy w zapisie `Rust > Ownership` są numerowane hierarchicznie jak w `generate_report`.
 pub fn format_checklist(items: &[String]) -> Vec<String> {
-    todo!()
+    checklist::format_items(&outline::items_from_topics(items))
 }
 
 /// Tworzy linię podsumowania z liczbą elementów i liczbą znaków bez spacji.
+/// Przy podtematach linia ma postać z `checklist::summarize_items`, a separatory `>`
+/// nie są liczone jako znaki tematu.
 pub fn build_summary(items: &[String]) -> String {
-    todo!()
+    checklist::summarize_items(&outline::items_from_topics(items))
 }
 
 /// Orkiestruje cały raport: zbiera tematy, buduje checklistę i dodaje linię podsumowania.
+/// Wejście może zawierać podtematy (`Rust > Ownership` albo wcięte linie, zob. moduł
+/// [`outline`]); dla zwykłej listy po przecinkach wynik jest taki jak w pierwotnym zadaniu.
 pub fn generate_report(line: &str) -> Result<Vec<String>, String> {
-    todo!()
+    let items = outline::parse_outline(line)?;
+    Ok(format_report(&items))
 }
 
-pub fn main() {
-    let mut buffer = String::new();
+/// Checklista z linią podsumowania, bez linii postępu (wynik `generate_report`).
+pub fn format_report(items: &[ChecklistItem]) -> Vec<String> {
+    let mut lines = checklist::format_items(items);
+    lines.push(checklist::summarize_items(items));
+    lines
+}
+
+/// Raport dla checklisty ze stanem pozycji: lista, linia podsumowania i linia postępu.
+/// Ten sam tekst jest zapisywany do pliku, więc można go ponownie wczytać.
+pub fn checklist_report(items: &[ChecklistItem]) -> Vec<String> {
+    render::render_checklist(items, OutputFormat::Text)
+}
+
+/// Wczytuje zapisaną checklistę i przełącza pozycje o podanych numerach.
+pub fn update_checklist(text: &str, toggles: &[String]) -> Result<Vec<ChecklistItem>, String> {
+    let mut items = checklist::parse_checklist(text)?;
+    for number in toggles {
+        checklist::toggle_item(&mut items, number)?;
+    }
+    Ok(items)
+}
+
+/// Ustawienia programu odczytane z argumentów wiersza poleceń.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Plik z checklistą aktualizowany w miejscu (`--plik`).
+    pub file: Option<String>,
+    /// Numery pozycji do przełączenia, np. `2` lub `1.3` (`--odhacz`, można podać wielokrotnie).
+    pub toggles: Vec<String>,
+    /// Format wyniku (`--format tekst|markdown|html|json`).
+    pub format: OutputFormat,
+    /// Próg odległości edycyjnej przy łączeniu duplikatów (`--scal` lub `--scal=<próg>`);
+    /// `None` wyłącza łączenie.
+    pub dedup: Option<usize>,
+    /// Czy wypisać plan powtórek zamiast checklisty (`--powtorki`).
+    pub review: bool,
+    /// Oceny powtórek w postaci `(numer, ocena)` (`--ocena 1.2=4`, można powtórzyć).
+    pub grades: Vec<(String, u8)>,
+    /// Data używana zamiast zegara systemowego (`--dzis RRRR-MM-DD`).
+    pub today: Option<schedule::Date>,
+}
 
-    if io::stdin().read_line(&mut buffer).is_err() {
-        println!("Nie udało się odczytać danych.");
-        return;
+/// Zwraca wartość flagi: podaną po `=` albo kolejny argument.
+fn take_value<I>(flag: &str, inline: Option<&str>, rest: &mut I) -> Result<String, String>
+where
+    I: Iterator<Item = String>,
+{
+    match inline {
+        Some(value) => Ok(value.to_string()),
+        None => rest
+            .next()
+            .ok_or_else(|| format!("Brak wartości dla {flag}")),
+    }
+}
+
+/// Odczytuje ustawienia z argumentów programu: `--plik <ścieżka>`, `--odhacz <numer>`,
+/// `--format <format>`, `--ocena <numer>=<ocena>`, `--dzis <data>` (wartości można też podać
+/// po `=`) oraz `--scal[=<próg>]` i `--powtorki`.
+pub fn parse_args<I>(args: I) -> Result<Options, String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut options = Options::default();
+    let mut args = args.into_iter().map(|arg| arg.as_ref().to_string());
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) => (flag, Some(value)),
+            None => (arg.as_str(), None),
+        };
+
+        match flag {
+            "--plik" => options.file = Some(take_value(flag, inline, &mut args)?),
+            "--odhacz" => {
+                let value = take_value(flag, inline, &mut args)?;
+                let parts = value.trim().trim_end_matches('.').split('.');
+                if !parts
+                    .map(|part| part.parse::<usize>())
+                    .all(|part| matches!(part, Ok(n) if n >= 1))
+                {
+                    return Err(format!("Niepoprawny numer pozycji: {value}"));
+                }
+                options.toggles.push(value);
+            }
+            "--format" => options.format = take_value(flag, inline, &mut args)?.parse()?,
+            "--scal" => {
+                options.dedup = Some(match inline {
+                    None => dedup::DEFAULT_THRESHOLD,
+                    Some(value) => value
+                        .trim()
+                        .parse::<usize>()
+                        .map_err(|_| format!("Niepoprawny próg: {value}"))?,
+                });
+            }
+            "--powtorki" if inline.is_none() => options.review = true,
+            "--ocena" => {
+                let value = take_value(flag, inline, &mut args)?;
+                let grade = value
+                    .split_once('=')
+                    .and_then(|(number, grade)| Some((number, grade.trim().parse::<u8>().ok()?)));
+                match grade {
+                    Some((number, grade)) if grade <= 5 => {
+                        options.grades.push((number.trim().to_string(), grade))
+                    }
+                    _ => {
+                        return Err(format!(
+                            "Niepoprawna ocena (oczekiwano numer=0..5): {value}"
+                        ))
+                    }
+                }
+            }
+            "--dzis" => options.today = Some(take_value(flag, inline, &mut args)?.parse()?),
+            _ => return Err(format!("Nieznany argument: {arg}")),
+        }
     }
 
-    match generate_report(buffer.as_str()) {
-        Ok(lines) => {
-            for line in lines {
+    if options.file.is_none() && !options.toggles.is_empty() {
+        return Err("Opcja --odhacz wymaga podania --plik.".to_string());
+    }
+    if options.file.is_none() && (options.review || !options.grades.is_empty()) {
+        return Err("Plan powtórek wymaga podania --plik.".to_string());
+    }
+    Ok(options)
+}
+
+/// Aktualizuje plik z checklistą i zwraca jej pozycje oraz raport połączonych duplikatów.
+/// Jeśli plik nie istnieje, checklista powstaje z tematów podanych na wejściu.
+/// Plik zawsze ma format tekstowy, żeby można go było ponownie wczytać.
+fn run_with_file<R: Read>(
+    path: &str,
+    options: &Options,
+    input: R,
+) -> Result<(Vec<ChecklistItem>, Vec<dedup::Merge>), String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            let input = read_all(input)?;
+            checklist::format_items(&outline::parse_outline(&input)?).join("\n")
+        }
+        Err(err) => return Err(format!("Nie udało się odczytać pliku {path}: {err}")),
+    };
+
+    let items = update_checklist(&text, &options.toggles)?;
+    let (items, merges) = deduplicate(items, options.dedup);
+    let mut content = checklist_report(&items).join("\n");
+    content.push('\n');
+    fs::write(path, content).map_err(|err| format!("Nie udało się zapisać pliku {path}: {err}"))?;
+    Ok((items, merges))
+}
+
+/// Łączy duplikaty, jeśli podano próg; w przeciwnym razie zwraca pozycje bez zmian.
+fn deduplicate(
+    items: Vec<ChecklistItem>,
+    threshold: Option<usize>,
+) -> (Vec<ChecklistItem>, Vec<dedup::Merge>) {
+    match threshold {
+        Some(threshold) => dedup::deduplicate(&items, threshold),
+        None => (items, Vec::new()),
+    }
+}
+
+/// Zapisuje oceny powtórek w pliku stanu obok checklisty i zwraca plan powtórek.
+pub fn run_reviews(
+    path: &str,
+    items: &[ChecklistItem],
+    grades: &[(String, u8)],
+    clock: &dyn Clock,
+) -> Result<Vec<String>, String> {
+    let state_path = schedule::state_path(path);
+    let mut states = match fs::read_to_string(&state_path) {
+        Ok(text) => schedule::parse_states(&text)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Default::default(),
+        Err(err) => return Err(format!("Nie udało się odczytać pliku {state_path}: {err}")),
+    };
+
+    if !grades.is_empty() {
+        for (number, grade) in grades {
+            schedule::record_review(&mut states, items, number, *grade, clock)?;
+        }
+        let mut content = schedule::format_states(&states).join("\n");
+        content.push('\n');
+        fs::write(&state_path, content)
+            .map_err(|err| format!("Nie udało się zapisać pliku {state_path}: {err}"))?;
+    }
+    Ok(schedule::review_report(items, &states, clock))
+}
+
+/// Wynik programu: linie dla standardowego wyjścia i dla standardowego wyjścia błędów.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Output {
+    pub stdout: Vec<String>,
+    pub stderr: Vec<String>,
+}
+
+/// Tworzy wynik programu z tematów podanych w `input` (zwykle standardowe wejście).
+/// Bez `--plik` format tekstowy jest taki jak w `generate_report`, a raport połączeń
+/// trafia pod checklistę (dla innych formatów — do `stderr`). Funkcja niczego nie
+/// wypisuje; robi to wywołujący.
+pub fn run<R: Read>(options: &Options, input: R) -> Result<Output, String> {
+    let (items, merges) = match &options.file {
+        Some(path) => run_with_file(path, options, input)?,
+        None => deduplicate(outline::parse_outline(&read_all(input)?)?, options.dedup),
+    };
+
+    if let (Some(path), true) = (&options.file, options.review || !options.grades.is_empty()) {
+        let clock: Box<dyn Clock> = match options.today {
+            Some(date) => Box::new(FixedClock(date)),
+            None => Box::new(SystemClock),
+        };
+        let stdout = run_reviews(path, &items, &options.grades, clock.as_ref())?;
+        return Ok(Output {
+            stdout,
+            stderr: Vec::new(),
+        });
+    }
+
+    let mut lines = match (&options.file, options.format) {
+        (None, OutputFormat::Text) => format_report(&items),
+        (_, format) => render::render_checklist(&items, format),
+    };
+    let mut notes = dedup::format_merges(&merges);
+    if options.format == OutputFormat::Text {
+        lines.append(&mut notes);
+    }
+    Ok(Output {
+        stdout: lines,
+        stderr: notes,
+    })
+}
+
+/// Wczytuje całe wejście (jedną linię tematów albo konspekt z wcięciami).
+fn read_all<R: Read>(mut input: R) -> Result<String, String> {
+    let mut buffer = String::new();
+    match input.read_to_string(&mut buffer) {
+        Ok(_) => Ok(buffer),
+        Err(_) => Err("Nie udało się odczytać danych.".to_string()),
+    }
+}
+
+pub fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            println!("{message}");
+            return;
+        }
+    };
+
+    match run(&options, io::stdin().lock()) {
+        Ok(output) => {
+            for line in output.stdout {
                 println!("{line}");
             }
+            for line in output.stderr {
+                eprintln!("{line}");
+            }
         }
         Err(message) => println!("{message}"),
     }
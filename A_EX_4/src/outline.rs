@@ -0,0 +1,128 @@
+//! Zamiana wejścia z podtematami na listę pozycji checklisty.
+//!
+//! Obsługiwane są dwa zapisy, które można łączyć:
+//! - ścieżki z separatorem `>`: `Rust > Ownership > Borrowing, Rust > Traits`,
+//! - wiele linii z wcięciami, gdzie każda wcięta linia jest podtematem poprzedniej
+//!   mniej wciętej linii (tabulator liczy się jak cztery spacje).
+//!
+//! Wejście z jedną linią jest dzielone po przecinkach jak w `collect_topics`,
+//! a w wejściu wieloliniowym każda linia to jeden temat.
+use crate::checklist::ChecklistItem;
+
+/// Separator kolejnych poziomów w zapisie ścieżkowym.
+pub const PATH_SEPARATOR: char = '>';
+
+/// Szerokość wcięcia linii.
+fn indent_width(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+/// Dzieli ścieżkę `a > b > c` na niepuste człony.
+fn split_path(entry: &str) -> Vec<&str> {
+    entry
+        .split(PATH_SEPARATOR)
+        .map(|part| part.trim())
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// Dodaje ścieżkę tematów pod pozycją `parent` (lub na najwyższym poziomie dla `None`).
+/// Istniejące tematy o tej samej nazwie są używane ponownie jako rodzice, więc `Rust > A`
+/// i `Rust > B` trafiają pod jeden temat `Rust`. Ostatni człon jest zawsze dodawany jako nowa
+/// pozycja, tak jak powtórzony temat w zwykłej liście. Zwraca indeks ostatniego członu ścieżki.
+pub fn insert_path(items: &mut Vec<ChecklistItem>, parent: Option<usize>, path: &[&str]) -> usize {
+    let mut parent = parent;
+    for (position, name) in path.iter().enumerate() {
+        let depth = parent.map_or(0, |index| items[index].depth + 1);
+        let start = parent.map_or(0, |index| index + 1);
+        let end = subtree_end(items, parent);
+
+        let is_last = position + 1 == path.len();
+        let existing = if is_last {
+            None
+        } else {
+            (start..end).find(|&index| items[index].depth == depth && items[index].topic == *name)
+        };
+        let index = match existing {
+            Some(index) => index,
+            None => {
+                items.insert(end, ChecklistItem::nested(*name, depth));
+                end
+            }
+        };
+        parent = Some(index);
+    }
+    parent.expect("ścieżka ma co najmniej jeden człon")
+}
+
+/// Indeks pierwszej pozycji za poddrzewem `parent` (dla `None` — koniec listy).
+pub(crate) fn subtree_end(items: &[ChecklistItem], parent: Option<usize>) -> usize {
+    match parent {
+        None => items.len(),
+        Some(index) => {
+            let depth = items[index].depth;
+            (index + 1..items.len())
+                .find(|&next| items[next].depth <= depth)
+                .unwrap_or(items.len())
+        }
+    }
+}
+
+/// Zamienia tematy (np. z `collect_topics`) na pozycje checklisty. Temat w zapisie
+/// ścieżkowym (`Rust > Ownership`) trafia pod swojego rodzica, a pozostałe są na najwyższym
+/// poziomie w podanej kolejności. Tematy bez żadnego członu są pomijane.
+pub fn items_from_topics(topics: &[String]) -> Vec<ChecklistItem> {
+    let mut items = Vec::new();
+    for topic in topics {
+        let path = split_path(topic);
+        if !path.is_empty() {
+            insert_path(&mut items, None, &path);
+        }
+    }
+    items
+}
+
+/// Buduje listę pozycji z tematami i podtematami.
+/// Zwraca błąd `"Brak tematów do przećwiczenia."`, jeśli wejście nie zawiera żadnego tematu.
+pub fn parse_outline(input: &str) -> Result<Vec<ChecklistItem>, String> {
+    let lines: Vec<&str> = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    let mut items = Vec::new();
+
+    if lines.len() <= 1 {
+        for entry in input.split(',') {
+            let path = split_path(entry);
+            if !path.is_empty() {
+                insert_path(&mut items, None, &path);
+            }
+        }
+    } else {
+        // Stos par (wcięcie, indeks pozycji) dla bieżącej gałęzi.
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        for line in lines {
+            let indent = indent_width(line);
+            let path = split_path(line);
+            if path.is_empty() {
+                continue;
+            }
+            while stack.last().is_some_and(|&(width, _)| width >= indent) {
+                stack.pop();
+            }
+            let parent = stack.last().map(|&(_, index)| index);
+            let index = insert_path(&mut items, parent, &path);
+            // Wstawienie mogło przesunąć pozycje za rodzicem, ale nie te na stosie,
+            // bo leżą one przed miejscem wstawienia.
+            stack.push((indent, index));
+        }
+    }
+
+    if items.is_empty() {
+        return Err("Brak tematów do przećwiczenia.".to_string());
+    }
+    Ok(items)
+}
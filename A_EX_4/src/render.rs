@@ -0,0 +1,171 @@
+//! Formaty wyniku checklisty: tekst, lista zadań Markdown (GFM), strona HTML i JSON.
+use std::str::FromStr;
+
+use crate::checklist::{self, ChecklistItem};
+
+/// Format wypisywanej checklisty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Numerowane linie `N. [ ] temat` z podsumowaniem (ten sam format co plik checklisty).
+    #[default]
+    Text,
+    /// Lista zadań GitHub-flavoured Markdown (`- [ ] temat`), podtematy wcięte.
+    Markdown,
+    /// Samodzielna strona HTML z zagnieżdżonymi listami i polami wyboru.
+    Html,
+    /// Obiekt JSON z pozycjami i liczbami podsumowania.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "tekst" | "text" => Ok(OutputFormat::Text),
+            "markdown" | "md" => Ok(OutputFormat::Markdown),
+            "html" => Ok(OutputFormat::Html),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("Nieznany format: {}", raw.trim())),
+        }
+    }
+}
+
+/// Formatuje checklistę w wybranym formacie. Każdy format zawiera stan pozycji
+/// oraz liczby z podsumowania i postępu.
+pub fn render_checklist(items: &[ChecklistItem], format: OutputFormat) -> Vec<String> {
+    match format {
+        OutputFormat::Text => render_text(items),
+        OutputFormat::Markdown => render_markdown(items),
+        OutputFormat::Html => render_html(items),
+        OutputFormat::Json => render_json(items),
+    }
+}
+
+fn render_text(items: &[ChecklistItem]) -> Vec<String> {
+    let mut lines = checklist::format_items(items);
+    lines.push(checklist::summarize_items(items));
+    lines.push(checklist::build_progress(items));
+    lines
+}
+
+fn render_markdown(items: &[ChecklistItem]) -> Vec<String> {
+    let mut lines: Vec<String> = items
+        .iter()
+        .map(|item| {
+            let mark = if item.done { 'x' } else { ' ' };
+            format!("{}- [{mark}] {}", "  ".repeat(item.depth), item.topic)
+        })
+        .collect();
+    lines.push(String::new());
+    lines.push(checklist::summarize_items(items));
+    lines.push(String::new());
+    lines.push(checklist::build_progress(items));
+    lines
+}
+
+fn render_html(items: &[ChecklistItem]) -> Vec<String> {
+    let mut lines = vec![
+        "<!DOCTYPE html>".to_string(),
+        "<html lang=\"pl\">".to_string(),
+        "<head>".to_string(),
+        "  <meta charset=\"utf-8\">".to_string(),
+        "  <title>Checklista</title>".to_string(),
+        "</head>".to_string(),
+        "<body>".to_string(),
+        "  <ul>".to_string(),
+    ];
+
+    // Pozycje są zapisane w kolejności drzewa, więc listę zagnieżdżoną otwieramy przed
+    // pierwszym podtematem i zamykamy, gdy kolejna pozycja wraca na wyższy poziom.
+    for (index, item) in items.iter().enumerate() {
+        let indent = "  ".repeat(2 * item.depth + 2);
+        let checked = if item.done { " checked" } else { "" };
+        let label = format!(
+            "<label><input type=\"checkbox\"{checked}> {}</label>",
+            html_escape(&item.topic)
+        );
+
+        if checklist::is_parent(items, index) {
+            lines.push(format!("{indent}<li>{label}"));
+            lines.push(format!("{indent}  <ul>"));
+            continue;
+        }
+        lines.push(format!("{indent}<li>{label}</li>"));
+
+        let next_depth = items.get(index + 1).map_or(0, |next| next.depth);
+        for depth in (next_depth..item.depth).rev() {
+            let indent = "  ".repeat(2 * depth + 2);
+            lines.push(format!("{indent}  </ul>"));
+            lines.push(format!("{indent}</li>"));
+        }
+    }
+
+    lines.push("  </ul>".to_string());
+    lines.push(format!(
+        "  <p>{}</p>",
+        html_escape(&checklist::summarize_items(items))
+    ));
+    lines.push(format!(
+        "  <p>{}</p>",
+        html_escape(&checklist::build_progress(items))
+    ));
+    lines.push("</body>".to_string());
+    lines.push("</html>".to_string());
+    lines
+}
+
+fn render_json(items: &[ChecklistItem]) -> Vec<String> {
+    let stats = checklist::checklist_stats(items);
+    let mut lines = vec!["{".to_string(), "  \"pozycje\": [".to_string()];
+
+    for (index, (item, number)) in items.iter().zip(checklist::item_numbers(items)).enumerate() {
+        let separator = if index + 1 < items.len() { "," } else { "" };
+        lines.push(format!(
+            "    {{\"numer\": {}, \"temat\": {}, \"poziom\": {}, \"gotowe\": {}}}{separator}",
+            json_string(&number),
+            json_string(&item.topic),
+            item.depth,
+            item.done
+        ));
+    }
+
+    lines.push("  ],".to_string());
+    lines.push(format!(
+        "  \"podsumowanie\": {{\"elementy\": {}, \"nadrzedne\": {}, \"koncowe\": {}, \"znaki\": {}, \"gotowe\": {}, \"procent\": {}}}",
+        stats.items, stats.parents, stats.leaves, stats.characters, stats.done, stats.percent
+    ));
+    lines.push("}".to_string());
+    lines
+}
+
+/// Zamienia znaki specjalne HTML na encje.
+fn html_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Zapisuje tekst jako literał JSON, escapując cudzysłowy, backslashe i znaki sterujące.
+fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,351 @@
+//! Planowanie powtórek tematów algorytmem w stylu SM-2.
+//!
+//! Każdy temat ma historię ocen (0–5), współczynnik łatwości, bieżący odstęp w dniach
+//! i termin następnej powtórki. Stan jest zapisywany w pliku tekstowym obok checklisty,
+//! a daty pochodzą z zegara ([`Clock`]), który w testach można zastąpić stałą datą.
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::checklist::{self, ChecklistItem};
+
+/// Początkowy współczynnik łatwości w setnych częściach (2.50).
+pub const INITIAL_EASE: u32 = 250;
+/// Najmniejszy dopuszczalny współczynnik łatwości (1.30).
+pub const MIN_EASE: u32 = 130;
+/// Najniższa ocena, przy której powtórka jest uznana za udaną.
+pub const PASSING_GRADE: u8 = 3;
+/// Najdłuższy odstęp między powtórkami w dniach (około 100 lat).
+pub const MAX_INTERVAL: u32 = 36_500;
+/// Zakres lat akceptowanych przez [`Date::from_ymd`] (format `RRRR`).
+pub const YEARS: std::ops::RangeInclusive<i64> = 1..=9999;
+
+/// Data kalendarzowa przechowywana jako liczba dni od 1970-01-01.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date(i64);
+
+impl Date {
+    /// Tworzy datę z roku, miesiąca i dnia; zwraca `None` dla nieistniejącej daty
+    /// lub roku spoza [`YEARS`].
+    pub fn from_ymd(year: i64, month: u32, day: u32) -> Option<Self> {
+        if !YEARS.contains(&year) || !(1..=12).contains(&month) || day == 0 || day > 31 {
+            return None;
+        }
+        let date = Date(days_from_civil(year, month, day));
+        (date.ymd() == (year, month, day)).then_some(date)
+    }
+
+    /// Rok, miesiąc i dzień.
+    pub fn ymd(self) -> (i64, u32, u32) {
+        civil_from_days(self.0)
+    }
+
+    pub fn add_days(self, days: i64) -> Self {
+        Date(self.0 + days)
+    }
+
+    /// Liczba dni od `earlier` do tej daty (ujemna, jeśli `earlier` jest późniejsza).
+    pub fn days_since(self, earlier: Date) -> i64 {
+        self.0 - earlier.0
+    }
+}
+
+/// Algorytm H. Hinnanta: data w kalendarzu gregoriańskim → dni od 1970-01-01.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let month_index = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Odwrotność [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = if days >= 0 { days } else { days - 146_096 } / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = self.ymd();
+        write!(f, "{year:04}-{month:02}-{day:02}")
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    /// Parsuje datę w formacie `RRRR-MM-DD`.
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let text = raw.trim();
+        let error = || format!("Niepoprawna data: {text}");
+        let parts: Vec<&str> = text.split('-').collect();
+        let [year, month, day] = parts.as_slice() else {
+            return Err(error());
+        };
+        let year = year.parse().map_err(|_| error())?;
+        let month = month.parse().map_err(|_| error())?;
+        let day = day.parse().map_err(|_| error())?;
+        Date::from_ymd(year, month, day).ok_or_else(error)
+    }
+}
+
+/// Źródło bieżącej daty.
+pub trait Clock {
+    fn today(&self) -> Date;
+}
+
+/// Zegar systemowy (data w strefie UTC).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn today(&self) -> Date {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() as i64)
+            .unwrap_or(0);
+        Date(seconds.div_euclid(86_400))
+    }
+}
+
+/// Zegar zawsze zwracający tę samą datę.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub Date);
+
+impl Clock for FixedClock {
+    fn today(&self) -> Date {
+        self.0
+    }
+}
+
+/// Stan powtórek jednego tematu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewState {
+    /// Liczba udanych powtórek z rzędu.
+    pub repetitions: u32,
+    /// Odstęp do następnej powtórki w dniach.
+    pub interval: u32,
+    /// Współczynnik łatwości w setnych częściach (`250` to 2.50).
+    pub ease: u32,
+    /// Termin następnej powtórki.
+    pub due: Date,
+    /// Daty i oceny wszystkich powtórek, od najstarszej.
+    pub history: Vec<(Date, u8)>,
+}
+
+impl ReviewState {
+    /// Stan nowego tematu: do powtórki od razu.
+    pub fn new(today: Date) -> Self {
+        Self {
+            repetitions: 0,
+            interval: 0,
+            ease: INITIAL_EASE,
+            due: today,
+            history: Vec::new(),
+        }
+    }
+
+    /// Zapisuje powtórkę z oceną `0..=5` i wyznacza kolejny termin według SM-2:
+    /// po udanych powtórkach odstęp wynosi 1 dzień, 6 dni, a potem poprzedni odstęp razy
+    /// łatwość (najwyżej [`MAX_INTERVAL`] dni); ocena poniżej 3 zaczyna naukę od nowa.
+    pub fn review(&mut self, grade: u8, today: Date) -> Result<(), String> {
+        if grade > 5 {
+            return Err(format!("Ocena musi być z zakresu 0-5: {grade}"));
+        }
+
+        if grade >= PASSING_GRADE {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => {
+                    let next = (u64::from(self.interval) * u64::from(self.ease) + 50) / 100;
+                    next.min(u64::from(MAX_INTERVAL)) as u32
+                }
+            };
+            self.repetitions = self.repetitions.saturating_add(1);
+        } else {
+            self.repetitions = 0;
+            self.interval = 1;
+        }
+
+        // EF' = EF + 0.1 - (5 - q) * (0.08 + (5 - q) * 0.02), liczone w setnych.
+        let miss = 5 - grade as i64;
+        let change = 10 - miss * (8 + miss * 2);
+        self.ease = (self.ease as i64 + change).max(MIN_EASE as i64) as u32;
+
+        self.due = today.add_days(self.interval as i64);
+        self.history.push((today, grade));
+        Ok(())
+    }
+}
+
+/// Nagłówek pliku stanu; linie zaczynające się od `#` są pomijane przy odczycie.
+const STATE_HEADER: &str = "# temat\tpowtórzenia\todstęp\tłatwość\ttermin\thistoria";
+
+/// Ścieżka pliku stanu dla checklisty, np. `nauka.txt` → `nauka.txt.powtorki`.
+pub fn state_path(checklist_path: &str) -> String {
+    format!("{checklist_path}.powtorki")
+}
+
+/// Wczytuje plik stanu: jedna linia na temat, pola rozdzielone tabulatorami,
+/// historia jako `data:ocena` po przecinkach.
+pub fn parse_states(text: &str) -> Result<BTreeMap<String, ReviewState>, String> {
+    let mut states = BTreeMap::new();
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error = || format!("Niepoprawna linia {} pliku powtórek: {line}", index + 1);
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [topic, repetitions, interval, ease, due, history] = fields.as_slice() else {
+            return Err(error());
+        };
+
+        let mut reviews = Vec::new();
+        for entry in history.split(',').filter(|entry| !entry.is_empty()) {
+            let (date, grade) = entry.split_once(':').ok_or_else(error)?;
+            reviews.push((
+                date.parse::<Date>()?,
+                grade.parse::<u8>().map_err(|_| error())?,
+            ));
+        }
+
+        let state = ReviewState {
+            repetitions: repetitions.parse().map_err(|_| error())?,
+            interval: interval.parse().map_err(|_| error())?,
+            ease: parse_ease(ease).ok_or_else(error)?,
+            due: due.parse()?,
+            history: reviews,
+        };
+        states.insert(topic.to_string(), state);
+    }
+    Ok(states)
+}
+
+/// Parsuje łatwość zapisaną jako `2.50`.
+fn parse_ease(text: &str) -> Option<u32> {
+    let (whole, fraction) = text.split_once('.')?;
+    if fraction.len() != 2 {
+        return None;
+    }
+    whole
+        .parse::<u32>()
+        .ok()?
+        .checked_mul(100)?
+        .checked_add(fraction.parse::<u32>().ok()?)
+}
+
+/// Zapisuje stan w formacie czytanym przez [`parse_states`].
+pub fn format_states(states: &BTreeMap<String, ReviewState>) -> Vec<String> {
+    let mut lines = vec![STATE_HEADER.to_string()];
+    for (topic, state) in states {
+        let history: Vec<String> = state
+            .history
+            .iter()
+            .map(|(date, grade)| format!("{date}:{grade}"))
+            .collect();
+        lines.push(format!(
+            "{topic}\t{}\t{}\t{}.{:02}\t{}\t{}",
+            state.repetitions,
+            state.interval,
+            state.ease / 100,
+            state.ease % 100,
+            state.due,
+            history.join(",")
+        ));
+    }
+    lines
+}
+
+/// Zapisuje ocenę powtórki pozycji o podanym numerze (`2` albo `1.3`) z datą z zegara.
+/// Tematy są rozpoznawane po pełnej ścieżce, więc zmiana numeracji nie gubi historii.
+pub fn record_review(
+    states: &mut BTreeMap<String, ReviewState>,
+    items: &[ChecklistItem],
+    number: &str,
+    grade: u8,
+    clock: &dyn Clock,
+) -> Result<(), String> {
+    let wanted = number.trim().trim_end_matches('.');
+    let index = checklist::item_numbers(items)
+        .iter()
+        .position(|candidate| candidate == wanted)
+        .ok_or_else(|| format!("Brak pozycji numer {}.", number.trim()))?;
+    let today = clock.today();
+    states
+        .entry(checklist::item_paths(items).swap_remove(index))
+        .or_insert_with(|| ReviewState::new(today))
+        .review(grade, today)
+}
+
+/// Raport powtórek: tematy do powtórzenia dziś (nowe i zaległe) oraz termin następnej
+/// powtórki każdego tematu z checklisty.
+pub fn review_report(
+    items: &[ChecklistItem],
+    states: &BTreeMap<String, ReviewState>,
+    clock: &dyn Clock,
+) -> Vec<String> {
+    let today = clock.today();
+    let numbers = checklist::item_numbers(items);
+    let paths = checklist::item_paths(items);
+
+    let mut due_today = Vec::new();
+    let mut plan = Vec::new();
+    for (number, path) in numbers.iter().zip(&paths) {
+        match states.get(path) {
+            None => {
+                due_today.push(format!("- {number}. {path} (nowy)"));
+                plan.push(format!("{number}. {path}: {today} (nowy)"));
+            }
+            Some(state) => {
+                let days = state.due.days_since(today);
+                if days < 0 {
+                    due_today.push(format!("- {number}. {path} (zaległy od {})", state.due));
+                } else if days == 0 {
+                    due_today.push(format!("- {number}. {path}"));
+                }
+                plan.push(format!(
+                    "{number}. {path}: {} ({}, łatwość {}.{:02}, powtórek {})",
+                    state.due,
+                    describe_days(days),
+                    state.ease / 100,
+                    state.ease % 100,
+                    state.history.len()
+                ));
+            }
+        }
+    }
+
+    let mut lines = vec![format!("Do powtórki dziś ({today}): {}", due_today.len())];
+    lines.extend(due_today);
+    lines.push("Plan powtórek:".to_string());
+    lines.extend(plan);
+    lines
+}
+
+fn describe_days(days: i64) -> String {
+    match days {
+        0 => "dziś".to_string(),
+        1 => "jutro".to_string(),
+        -1 => "wczoraj".to_string(),
+        d if d < 0 => format!("{} dni temu", -d),
+        d => format!("za {d} dni"),
+    }
+}
@@ -0,0 +1,56 @@
+use std::fs;
+
+use a_ex_4::checklist::{build_progress, parse_checklist, toggle_item, ChecklistItem};
+use a_ex_4::{checklist_report, parse_args, run, update_checklist, Options};
+
+#[test]
+fn saved_checklist_is_parsed_without_summary_lines() {
+    let text = "1. [ ] zmienne\n3. [X] pętle\n\nPodsumowanie: 2 elementów, 12 znaków (bez spacji)\nPostęp: 1/2 ukończone, 50%\n";
+    let items = parse_checklist(text).unwrap();
+    assert_eq!(items, vec![
+        ChecklistItem::new("zmienne"),
+        ChecklistItem { topic: "pętle".to_string(), done: true, depth: 0 },
+    ]);
+    assert_eq!(parse_checklist("1. [?] zmienne").unwrap_err(), "Niepoprawna linia 1: 1. [?] zmienne");
+    assert_eq!(parse_checklist("1. [ ] a\n1.1.1. [ ] b").unwrap_err(), "Niepoprawna linia 2: 1.1.1. [ ] b");
+    assert_eq!(parse_checklist("\n").unwrap_err(), "Brak tematów do przećwiczenia.");
+}
+
+#[test]
+fn toggling_updates_progress() {
+    let mut items = update_checklist("1. [ ] a\n2. [ ] b\n3. [ ] c", &["2.".to_string()]).unwrap();
+    assert_eq!(build_progress(&items), "Postęp: 1/3 ukończone, 33%");
+    assert_eq!(toggle_item(&mut items, "2"), Ok(false));
+    assert_eq!(toggle_item(&mut items, "4"), Err("Brak pozycji numer 4.".to_string()));
+    assert_eq!(checklist_report(&items), vec![
+        "1. [ ] a",
+        "2. [ ] b",
+        "3. [ ] c",
+        "Podsumowanie: 3 elementów, 3 znaków (bez spacji)",
+        "Postęp: 0/3 ukończone, 0%",
+    ]);
+}
+
+#[test]
+fn toggles_require_a_file() {
+    assert_eq!(parse_args(["--odhacz", "1"]).unwrap_err(), "Opcja --odhacz wymaga podania --plik.");
+    assert_eq!(parse_args(["--plik"]).unwrap_err(), "Brak wartości dla --plik");
+    assert_eq!(parse_args(["--inne"]).unwrap_err(), "Nieznany argument: --inne");
+}
+
+#[test]
+fn file_is_created_from_input_and_updated_in_place() {
+    let path = std::env::temp_dir().join(format!("a_ex_4_checklist_{}.txt", std::process::id()));
+    let path = path.display().to_string();
+    let _ = fs::remove_file(&path);
+
+    let options = Options { file: Some(path.clone()), ..Options::default() };
+    let created = run(&options, "zmienne, pętle".as_bytes()).unwrap().stdout;
+    assert_eq!(created[0], "1. [ ] zmienne");
+
+    let options = parse_args(["--plik", path.as_str(), "--odhacz=2"]).unwrap();
+    let updated = run(&options, "ignorowane".as_bytes()).unwrap().stdout;
+    assert_eq!(updated.last().unwrap(), "Postęp: 1/2 ukończone, 50%");
+    assert_eq!(fs::read_to_string(&path).unwrap(), updated.join("\n") + "\n");
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,73 @@
+use a_ex_4::checklist::{item_numbers, ChecklistItem};
+use a_ex_4::outline::{insert_path, items_from_topics, parse_outline};
+use a_ex_4::{build_summary, collect_topics, format_checklist, generate_report};
+
+fn topics(items: &[ChecklistItem]) -> Vec<(usize, &str)> {
+    items.iter().map(|item| (item.depth, item.topic.as_str())).collect()
+}
+
+#[test]
+fn paths_share_parents_and_get_nested_numbers() {
+    let items = parse_outline("Rust > Ownership > Borrowing, Rust > Traits, Python").unwrap();
+    assert_eq!(topics(&items), vec![
+        (0, "Rust"),
+        (1, "Ownership"),
+        (2, "Borrowing"),
+        (1, "Traits"),
+        (0, "Python"),
+    ]);
+    assert_eq!(item_numbers(&items), vec!["1", "1.1", "1.1.1", "1.2", "2"]);
+}
+
+#[test]
+fn indented_lines_are_subtopics() {
+    let items = parse_outline("Rust\n  Ownership\n  Traits\n\tGenerics\nPython\n").unwrap();
+    assert_eq!(topics(&items), vec![
+        (0, "Rust"),
+        (1, "Ownership"),
+        (1, "Traits"),
+        (2, "Generics"),
+        (0, "Python"),
+    ]);
+    assert_eq!(parse_outline(" , > ,").unwrap_err(), "Brak tematów do przećwiczenia.");
+}
+
+#[test]
+fn insert_path_reuses_parents_but_repeats_the_last_topic() {
+    let mut items = Vec::new();
+    assert_eq!(insert_path(&mut items, None, &["Rust", "Traits"]), 1);
+    assert_eq!(insert_path(&mut items, None, &["Python"]), 2);
+    assert_eq!(insert_path(&mut items, None, &["Rust", "Traits"]), 2);
+    assert_eq!(topics(&items), vec![(0, "Rust"), (1, "Traits"), (1, "Traits"), (0, "Python")]);
+}
+
+#[test]
+fn flat_topics_keep_the_original_report() {
+    let items = collect_topics("zmienne, pętle").unwrap();
+    assert_eq!(format_checklist(&items), vec!["1. [ ] zmienne", "2. [ ] pętle"]);
+    assert_eq!(build_summary(&items), "Podsumowanie: 2 elementów, 12 znaków (bez spacji)");
+    assert_eq!(generate_report("zmienne, pętle").unwrap(), vec![
+        "1. [ ] zmienne",
+        "2. [ ] pętle",
+        "Podsumowanie: 2 elementów, 12 znaków (bez spacji)",
+    ]);
+}
+
+#[test]
+fn checklist_helpers_use_the_outline_numbering() {
+    let items = collect_topics("Rust > Ownership, Rust > Traits, Python").unwrap();
+    assert_eq!(items_from_topics(&items).len(), 4);
+    assert_eq!(format_checklist(&items), vec![
+        "1. [ ] Rust",
+        "  1.1. [ ] Ownership",
+        "  1.2. [ ] Traits",
+        "2. [ ] Python",
+    ]);
+    assert_eq!(
+        build_summary(&items),
+        "Podsumowanie: 4 elementów (1 nadrzędnych, 3 końcowych), 25 znaków (bez spacji)"
+    );
+    let mut report = format_checklist(&items);
+    report.push(build_summary(&items));
+    assert_eq!(generate_report("Rust > Ownership, Rust > Traits, Python").unwrap(), report);
+}
@@ -0,0 +1,62 @@
+use a_ex_4::outline::parse_outline;
+use a_ex_4::render::{render_checklist, OutputFormat};
+use a_ex_4::{parse_args, run};
+
+#[test]
+fn formats_are_parsed_in_both_languages() {
+    assert_eq!("tekst".parse(), Ok(OutputFormat::Text));
+    assert_eq!(" MD ".parse(), Ok(OutputFormat::Markdown));
+    assert_eq!("html".parse(), Ok(OutputFormat::Html));
+    assert_eq!("xml".parse::<OutputFormat>(), Err("Nieznany format: xml".to_string()));
+    assert_eq!(parse_args(["--format=json"]).unwrap().format, OutputFormat::Json);
+}
+
+#[test]
+fn markdown_is_a_nested_task_list() {
+    let mut items = parse_outline("Rust > Traits, Go").unwrap();
+    items[1].done = true;
+    assert_eq!(render_checklist(&items, OutputFormat::Markdown), vec![
+        "- [ ] Rust",
+        "  - [x] Traits",
+        "- [ ] Go",
+        "",
+        "Podsumowanie: 3 elementów (1 nadrzędnych, 2 końcowych), 12 znaków (bez spacji)",
+        "",
+        "Postęp: 1/3 ukończone, 33%",
+    ]);
+}
+
+#[test]
+fn html_nests_lists_and_escapes_topics() {
+    let items = parse_outline("A > B > <C & D, E").unwrap();
+    let html = render_checklist(&items, OutputFormat::Html);
+    assert_eq!(html[7..17], [
+        "  <ul>",
+        "    <li><label><input type=\"checkbox\"> A</label>",
+        "      <ul>",
+        "        <li><label><input type=\"checkbox\"> B</label>",
+        "          <ul>",
+        "            <li><label><input type=\"checkbox\"> &lt;C &amp; D</label></li>",
+        "          </ul>",
+        "        </li>",
+        "      </ul>",
+        "    </li>",
+    ]);
+    assert_eq!(html[17], "    <li><label><input type=\"checkbox\"> E</label></li>");
+    assert_eq!(html.last().unwrap(), "</html>");
+}
+
+#[test]
+fn json_lists_items_and_summary() {
+    let lines = run(&parse_args(["--format", "json"]).unwrap(), "Rust > \"Traits\", Go".as_bytes()).unwrap().stdout;
+    assert_eq!(lines, vec![
+        "{",
+        "  \"pozycje\": [",
+        "    {\"numer\": \"1\", \"temat\": \"Rust\", \"poziom\": 0, \"gotowe\": false},",
+        "    {\"numer\": \"1.1\", \"temat\": \"\\\"Traits\\\"\", \"poziom\": 1, \"gotowe\": false},",
+        "    {\"numer\": \"2\", \"temat\": \"Go\", \"poziom\": 0, \"gotowe\": false}",
+        "  ],",
+        "  \"podsumowanie\": {\"elementy\": 3, \"nadrzedne\": 1, \"koncowe\": 2, \"znaki\": 14, \"gotowe\": 0, \"procent\": 0}",
+        "}",
+    ]);
+}
@@ -0,0 +1,58 @@
+use a_ex_4::dedup::{deduplicate, edit_distance, format_merges, is_duplicate, normalize_topic};
+use a_ex_4::outline::parse_outline;
+use a_ex_4::{parse_args, run};
+
+#[test]
+fn distance_and_normalization() {
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    assert_eq!(edit_distance("żółw", "zółw"), 1);
+    assert_eq!(edit_distance("", "abc"), 3);
+    assert_eq!(normalize_topic("  Pętle   FOR "), "pętle for");
+}
+
+#[test]
+fn short_topics_need_an_exact_match() {
+    assert!(is_duplicate("Traits", "traits", 0));
+    assert!(is_duplicate("Traits", "Trait", 1));
+    assert!(!is_duplicate("Traits", "Trait", 0));
+    assert!(!is_duplicate("Go", "C", 1));
+    assert!(!is_duplicate("abc", "abd", 2));
+}
+
+#[test]
+fn duplicates_are_merged_under_the_same_parent() {
+    let mut items = parse_outline("Rust > Traits, rust > traits, Python > Traits, Rust > Trait").unwrap();
+    items[2].done = true;
+    let (merged, merges) = deduplicate(&items, 1);
+    let topics: Vec<(usize, &str, bool)> =
+        merged.iter().map(|item| (item.depth, item.topic.as_str(), item.done)).collect();
+    assert_eq!(topics, vec![(0, "Rust", false), (1, "Traits", true), (0, "Python", false), (1, "Traits", false)]);
+    assert_eq!(format_merges(&merges), vec![
+        "Połączono: Trait → Traits",
+        "Połączono: rust → Rust",
+        "Połączono: traits → Traits",
+    ]);
+}
+
+#[test]
+fn merges_are_reported_under_the_text_checklist() {
+    let options = parse_args(["--scal"]).unwrap();
+    assert_eq!(run(&options, "Pętle, pętle , Petle, Go, C".as_bytes()).unwrap().stdout, vec![
+        "1. [ ] Pętle",
+        "2. [ ] Go",
+        "3. [ ] C",
+        "Podsumowanie: 3 elementów, 8 znaków (bez spacji)",
+        "Połączono: pętle → Pętle",
+        "Połączono: Petle → Pętle",
+    ]);
+    assert_eq!(parse_args(["--scal=x"]).unwrap_err(), "Niepoprawny próg: x");
+}
+
+#[test]
+fn merges_go_to_stderr_for_other_formats() {
+    let options = parse_args(["--scal", "--format", "markdown"]).unwrap();
+    let output = run(&options, "Pętle, pętle, Go".as_bytes()).unwrap();
+    assert_eq!(output.stdout[..2], ["- [ ] Pętle", "- [ ] Go"]);
+    assert!(!output.stdout.iter().any(|line| line.starts_with("Połączono")));
+    assert_eq!(output.stderr, vec!["Połączono: pętle → Pętle"]);
+}
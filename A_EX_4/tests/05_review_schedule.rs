@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+
+use a_ex_4::outline::parse_outline;
+use a_ex_4::schedule::{
+    format_states, parse_states, record_review, review_report, Date, FixedClock, ReviewState,
+    MAX_INTERVAL, MIN_EASE,
+};
+
+fn date(text: &str) -> Date {
+    text.parse().unwrap()
+}
+
+#[test]
+fn dates_round_trip_through_text() {
+    assert_eq!(date("2024-02-29").to_string(), "2024-02-29");
+    assert_eq!(date("2024-02-28").add_days(2).to_string(), "2024-03-01");
+    assert_eq!(date("2025-01-01").days_since(date("2024-12-25")), 7);
+    assert_eq!("2023-02-29".parse::<Date>(), Err("Niepoprawna data: 2023-02-29".to_string()));
+    assert_eq!(Date::from_ymd(1969, 12, 31).unwrap().to_string(), "1969-12-31");
+}
+
+#[test]
+fn years_outside_four_digits_are_rejected() {
+    assert_eq!(date("0001-01-01").to_string(), "0001-01-01");
+    assert_eq!(date("9999-12-31").to_string(), "9999-12-31");
+    assert_eq!(Date::from_ymd(0, 1, 1), None);
+    assert_eq!(Date::from_ymd(10_000, 1, 1), None);
+    assert_eq!(Date::from_ymd(i64::MAX, 1, 1), None);
+    assert_eq!(
+        "99999999999999999-01-01".parse::<Date>(),
+        Err("Niepoprawna data: 99999999999999999-01-01".to_string())
+    );
+}
+
+#[test]
+fn sm2_intervals_and_ease() {
+    let today = date("2026-10-18");
+    let mut state = ReviewState::new(today);
+    let intervals: Vec<u32> = [5, 4, 3]
+        .iter()
+        .map(|&grade| {
+            state.review(grade, today).unwrap();
+            state.interval
+        })
+        .collect();
+    assert_eq!(intervals, vec![1, 6, 16]);
+    assert_eq!(state.ease, 246);
+    assert_eq!(state.due, date("2026-11-03"));
+
+    state.review(0, today).unwrap();
+    assert_eq!((state.repetitions, state.interval), (0, 1));
+    assert_eq!(state.review(6, today), Err("Ocena musi być z zakresu 0-5: 6".to_string()));
+
+    let mut hard = ReviewState::new(today);
+    for _ in 0..10 {
+        hard.review(3, today).unwrap();
+    }
+    assert_eq!(hard.ease, MIN_EASE);
+}
+
+#[test]
+fn many_good_reviews_stop_at_the_maximum_interval() {
+    let mut today = date("2026-10-18");
+    let mut state = ReviewState::new(today);
+    for _ in 0..1_000 {
+        state.review(5, today).unwrap();
+        today = state.due;
+    }
+    assert_eq!(state.interval, MAX_INTERVAL);
+    assert_eq!(state.repetitions, 1_000);
+    assert_eq!(state.due.days_since(state.history.last().unwrap().0), MAX_INTERVAL as i64);
+}
+
+#[test]
+fn states_round_trip_and_report_due_topics() {
+    let items = parse_outline("Rust > Ownership, Python").unwrap();
+    let clock = FixedClock(date("2026-10-18"));
+    let mut states = BTreeMap::new();
+    record_review(&mut states, &items, "1.1.", 4, &clock).unwrap();
+    assert_eq!(
+        record_review(&mut states, &items, "7", 4, &clock),
+        Err("Brak pozycji numer 7.".to_string())
+    );
+
+    let text = format_states(&states).join("\n");
+    assert_eq!(parse_states(&text).unwrap(), states);
+    assert!(parse_states("Rust\t1\t1\t99999999999.00\t2026-10-19\t")
+        .unwrap_err()
+        .starts_with("Niepoprawna linia 1"));
+
+    assert_eq!(review_report(&items, &states, &clock), vec![
+        "Do powtórki dziś (2026-10-18): 2",
+        "- 1. Rust (nowy)",
+        "- 2. Python (nowy)",
+        "Plan powtórek:",
+        "1. Rust: 2026-10-18 (nowy)",
+        "1.1. Rust > Ownership: 2026-10-19 (jutro, łatwość 2.50, powtórek 1)",
+        "2. Python: 2026-10-18 (nowy)",
+    ]);
+}
@@ -1,35 +1,248 @@
-use std::io;
+use std::env;
+use std::io::{self, BufRead};
 
-/// Parsuje liczby całkowite rozdzielone białymi znakami.
+pub mod outliers;
+pub mod stats;
+pub mod stream;
+pub mod syntax;
+
+use stats::Statistic;
+use stream::{IntWidth, StreamSummary};
+
+/// Parsuje liczby całkowite rozdzielone białymi znakami, przecinkami lub średnikami
+/// (także `0x1F`, `0b101`, `0o17` i zakresy `1..=10`, zob. [`syntax`]).
 /// Zwraca komunikat `"Brak liczb do przeanalizowania."`, jeśli po przetworzeniu nie ma żadnych wartości.
 pub fn parse_numbers(input: &str) -> Result<Vec<i32>, String> {
-    todo!()
+    let numbers = syntax::parse_integers(input, IntWidth::I32)
+        .map_err(|errors| format!("Niepoprawna liczba: {}", errors[0].token))?
+        .into_iter()
+        .map(|number| number as i32)
+        .collect::<Vec<i32>>();
+
+    if numbers.is_empty() {
+        return Err("Brak liczb do przeanalizowania.".to_string());
+    }
+    Ok(numbers)
 }
 
 /// Zwraca krotkę (liczba elementów, minimum, maksimum, suma) dla przekazanych liczb.
-pub fn summarize_numbers(numbers: &[i32]) -> (usize, i32, i32, i32) {
-    todo!()
+/// Suma jest typu `i128`, więc jest dokładna dla każdego wycinka (np. `[i32::MAX, 1]`
+/// daje `2147483648`).
+pub fn summarize_numbers(numbers: &[i32]) -> (usize, i32, i32, i128) {
+    let mut min = i32::MAX;
+    let mut max = i32::MIN;
+    let mut sum = 0i128;
+    for &number in numbers {
+        min = min.min(number);
+        max = max.max(number);
+        sum += i128::from(number);
+    }
+    (numbers.len(), min, max, sum)
 }
 
 /// Buduje cztery linie raportu na podstawie przekazanych liczb.
 pub fn describe_numbers(numbers: &[i32]) -> Vec<String> {
-    todo!()
+    let numbers: Vec<i128> = numbers.iter().map(|&n| i128::from(n)).collect();
+    describe_integers(&numbers)
+}
+
+/// Podstawowy raport dla liczb z dowolnego zakresu (`--typ`); suma jest dokładna.
+pub fn describe_integers(numbers: &[i128]) -> Vec<String> {
+    let mut summary = StreamSummary::default();
+    for &number in numbers {
+        summary.push(number);
+    }
+    let value = |v: Option<i128>| v.map_or_else(String::new, |v| v.to_string());
+    let joined = numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    vec![
+        format!("Liczby ({}): {}", summary.count, joined),
+        format!("Minimum: {}", value(summary.min)),
+        format!("Maksimum: {}", value(summary.max)),
+        format!("Suma: {}", summary.sum),
+    ]
+}
+
+/// Buduje raport z wybranych statystyk w podanej kolejności.
+/// Dla [`Statistic::BASIC`] wynik jest taki sam jak z `describe_numbers`.
+pub fn describe_selected(numbers: &[i32], statistics: &[Statistic]) -> Vec<String> {
+    let numbers: Vec<i128> = numbers.iter().map(|&n| i128::from(n)).collect();
+    describe_selected_integers(&numbers, statistics)
+}
+
+/// Raport z wybranych statystyk dla liczb całkowitych. Linie podstawowego raportu
+/// (liczby, minimum, maksimum i suma) są liczone dokładnie jak w `describe_integers`,
+/// a pozostałe statystyki na wartościach `f64`.
+pub fn describe_selected_integers(numbers: &[i128], statistics: &[Statistic]) -> Vec<String> {
+    let basic = describe_integers(numbers);
+    let values: Vec<f64> = numbers.iter().map(|&n| n as f64).collect();
+    statistics
+        .iter()
+        .flat_map(|&statistic| {
+            let basic_line = Statistic::BASIC.iter().position(|&line| line == statistic);
+            match basic_line {
+                Some(index) => vec![basic[index].clone()],
+                None => stats::render_statistic(&values, statistic),
+            }
+        })
+        .collect()
+}
+
+/// Raport z wybranych statystyk dla dowolnych wartości (np. liczb rzeczywistych).
+pub fn describe_values(values: &[f64], statistics: &[Statistic]) -> Vec<String> {
+    statistics
+        .iter()
+        .flat_map(|&statistic| stats::render_statistic(values, statistic))
+        .collect()
 }
 
 /// Odpowiada za pełną analizę: parsowanie wejścia i przygotowanie raportu.
 pub fn run_analysis(line: &str) -> Result<Vec<String>, String> {
-    todo!()
+    let numbers = parse_numbers(line)?;
+    Ok(describe_numbers(&numbers))
 }
 
-pub fn main() {
-    let mut buffer = String::new();
+/// Analiza z wybranymi statystykami zamiast podstawowego raportu.
+pub fn run_analysis_with(line: &str, statistics: &[Statistic]) -> Result<Vec<String>, String> {
+    let numbers = parse_numbers(line)?;
+    Ok(describe_selected(&numbers, statistics))
+}
+
+/// Analiza wywoływana przez program: wszystkie błędne tokeny są zgłaszane naraz,
+/// każdy z położeniem i podpowiedzią. Bez `--pokaz` wypisywany jest podstawowy raport,
+/// a z `--odstajace` dochodzi do niego raport wartości odstających.
+pub fn run_program(line: &str, options: &Options) -> Result<Vec<String>, String> {
+    let errors = |errors: Vec<syntax::TokenError>| syntax::format_errors(&errors);
+    let statistics = if options.statistics.is_empty() {
+        &Statistic::BASIC[..]
+    } else {
+        &options.statistics
+    };
+
+    let (values, mut report) = if options.floats {
+        let values = syntax::parse_floats(line).map_err(errors)?;
+        let report = describe_values(&values, statistics);
+        (values, report)
+    } else {
+        let width = options.width.unwrap_or(IntWidth::I32);
+        let numbers = syntax::parse_integers(line, width).map_err(errors)?;
+        let values: Vec<f64> = numbers.iter().map(|&n| n as f64).collect();
+        (values, describe_selected_integers(&numbers, statistics))
+    };
+    if values.is_empty() {
+        return Err("Brak liczb do przeanalizowania.".to_string());
+    }
+
+    if let Some(methods) = &options.outliers {
+        report.extend(outliers::outlier_report(&values, methods, statistics));
+    }
+    Ok(report)
+}
 
-    if io::stdin().read_line(&mut buffer).is_err() {
-        println!("Nie udało się odczytać danych.");
-        return;
+/// Ustawienia programu odczytane z argumentów wiersza poleceń.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Options {
+    /// Statystyki do wypisania (`--pokaz srednia,mediana,p90`); pusta lista oznacza
+    /// podstawowy raport.
+    pub statistics: Vec<Statistic>,
+    /// Analiza strumieniowa całego wejścia bez zapamiętywania liczb (`--strumien`).
+    pub stream: bool,
+    /// Zakres liczb całkowitych (`--typ i32|i64|i128`); domyślnie `i32`, a w analizie
+    /// strumieniowej `i64`.
+    pub width: Option<IntWidth>,
+    /// Liczby rzeczywiste zamiast całkowitych (`--rzeczywiste`).
+    pub floats: bool,
+    /// Metody wykrywania wartości odstających (`--odstajace` lub `--odstajace=iqr=3,z=2.5`).
+    pub outliers: Option<Vec<outliers::Method>>,
+}
+
+/// Zwraca wartość flagi: podaną po `=` albo kolejny argument.
+fn take_value<I>(flag: &str, inline: Option<&str>, rest: &mut I) -> Result<String, String>
+where
+    I: Iterator<Item = String>,
+{
+    match inline {
+        Some(value) => Ok(value.to_string()),
+        None => rest
+            .next()
+            .ok_or_else(|| format!("Brak wartości dla {flag}")),
+    }
+}
+
+/// Odczytuje ustawienia z argumentów programu: `--pokaz <lista statystyk>`, `--strumien`,
+/// `--typ <typ>`, `--rzeczywiste` i `--odstajace[=<metody>]` (wartości można też podać po `=`).
+pub fn parse_args<I>(args: I) -> Result<Options, String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut options = Options::default();
+    let mut args = args.into_iter().map(|arg| arg.as_ref().to_string());
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) => (flag, Some(value)),
+            None => (arg.as_str(), None),
+        };
+
+        match flag {
+            "--pokaz" => {
+                let list = take_value(flag, inline, &mut args)?;
+                options.statistics = stats::parse_statistics(&list)?;
+            }
+            "--strumien" if inline.is_none() => options.stream = true,
+            "--typ" => options.width = Some(take_value(flag, inline, &mut args)?.parse()?),
+            "--rzeczywiste" if inline.is_none() => options.floats = true,
+            "--odstajace" => {
+                options.outliers = Some(match inline {
+                    None => outliers::Method::DEFAULT.to_vec(),
+                    Some(list) => outliers::parse_methods(list)?,
+                });
+            }
+            _ => return Err(format!("Nieznany argument: {arg}")),
+        }
+    }
+
+    if options.stream && !options.statistics.is_empty() {
+        return Err("Opcji --pokaz nie można łączyć z --strumien.".to_string());
     }
+    if options.stream && options.outliers.is_some() {
+        return Err("Opcji --odstajace nie można łączyć z --strumien.".to_string());
+    }
+    if options.floats && (options.stream || options.width.is_some()) {
+        return Err("Opcji --rzeczywiste nie można łączyć z --strumien ani --typ.".to_string());
+    }
+    Ok(options)
+}
+
+/// Uruchamia analizę na danych z `input`: w trybie `--strumien` całe wejście,
+/// w przeciwnym razie pierwszą linię.
+pub fn run<R: BufRead>(options: &Options, mut input: R) -> Result<Vec<String>, String> {
+    if options.stream {
+        let summary = stream::analyze_reader(input, options.width.unwrap_or_default())?;
+        return Ok(stream::describe_summary(&summary));
+    }
+
+    let mut buffer = String::new();
+    if input.read_line(&mut buffer).is_err() {
+        return Err("Nie udało się odczytać danych.".to_string());
+    }
+    run_program(buffer.as_str(), options)
+}
+
+pub fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            println!("{message}");
+            return;
+        }
+    };
 
-    match run_analysis(buffer.as_str()) {
+    match run(&options, io::stdin().lock()) {
         Ok(report) => {
             for line in report {
                 println!("{line}");
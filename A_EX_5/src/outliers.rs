@@ -0,0 +1,200 @@
+//! Wykrywanie wartości odstających metodą rozstępu międzykwartylowego (IQR) i na podstawie
+//! standaryzowanego odchylenia (z-score), wraz ze statystykami policzonymi bez nich.
+use std::str::FromStr;
+
+use crate::stats::{self, format_number, Statistic};
+
+/// Domyślny mnożnik IQR (granice `Q1 - 1.5·IQR` i `Q3 + 1.5·IQR`).
+pub const DEFAULT_IQR_FACTOR: f64 = 1.5;
+
+/// Domyślny próg z-score.
+pub const DEFAULT_Z_THRESHOLD: f64 = 3.0;
+
+/// Metoda wykrywania wraz z progiem.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Method {
+    /// Wartość leży poza granicami `[Q1 - k·IQR, Q3 + k·IQR]`.
+    Iqr(f64),
+    /// Wartość bezwzględna z-score (odległość od średniej w odchyleniach standardowych)
+    /// przekracza próg.
+    ZScore(f64),
+}
+
+impl Method {
+    /// Obie metody z domyślnymi progami.
+    pub const DEFAULT: [Method; 2] = [
+        Method::Iqr(DEFAULT_IQR_FACTOR),
+        Method::ZScore(DEFAULT_Z_THRESHOLD),
+    ];
+}
+
+impl FromStr for Method {
+    type Err = String;
+
+    /// Nazwy: `iqr`, `iqr=<mnożnik>`, `z` i `z=<próg>`.
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let raw = raw.trim();
+        let (name, threshold) = match raw.split_once('=') {
+            Some((name, value)) => {
+                let threshold = value
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|t| t.is_finite() && *t > 0.0)
+                    .ok_or_else(|| format!("Niepoprawny próg: {}", value.trim()))?;
+                (name.trim(), Some(threshold))
+            }
+            None => (raw, None),
+        };
+        match name.to_lowercase().as_str() {
+            "iqr" => Ok(Method::Iqr(threshold.unwrap_or(DEFAULT_IQR_FACTOR))),
+            "z" => Ok(Method::ZScore(threshold.unwrap_or(DEFAULT_Z_THRESHOLD))),
+            _ => Err(format!("Nieznana metoda wykrywania: {raw}")),
+        }
+    }
+}
+
+/// Parsuje listę metod rozdzieloną przecinkami, np. `iqr=3,z=2.5`.
+pub fn parse_methods(list: &str) -> Result<Vec<Method>, String> {
+    let methods = list
+        .split(',')
+        .filter(|name| !name.trim().is_empty())
+        .map(str::parse)
+        .collect::<Result<Vec<Method>, String>>()?;
+    if methods.is_empty() {
+        return Err("Nie wybrano żadnej metody wykrywania.".to_string());
+    }
+    Ok(methods)
+}
+
+/// Wartość uznana za odstającą.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outlier {
+    /// Pozycja wartości na wejściu liczona od 1 (po rozwinięciu zakresów).
+    pub position: usize,
+    pub value: f64,
+    /// Metody, które oznaczyły wartość.
+    pub methods: Vec<Method>,
+}
+
+/// Granice `(dolna, górna)` metody IQR.
+pub fn iqr_fences(values: &[f64], factor: f64) -> Option<(f64, f64)> {
+    let (q1, _, q3) = stats::quartiles(values)?;
+    let spread = q3 - q1;
+    Some((q1 - factor * spread, q3 + factor * spread))
+}
+
+/// Z-score wartości; `None`, gdy wszystkie wartości są równe.
+pub fn z_score(values: &[f64], value: f64) -> Option<f64> {
+    let mean = stats::mean(values)?;
+    let std_dev = stats::std_dev(values)?;
+    (std_dev > 0.0).then(|| (value - mean) / std_dev)
+}
+
+/// Granice `(dolna, górna)`, poza którymi metoda uznaje wartość za odstającą.
+/// Dla z-score to `średnia ± próg·odchylenie`; `None`, gdy nie da się ich wyznaczyć.
+pub fn bounds(values: &[f64], method: Method) -> Option<(f64, f64)> {
+    match method {
+        Method::Iqr(factor) => iqr_fences(values, factor),
+        Method::ZScore(threshold) => {
+            let mean = stats::mean(values)?;
+            let std_dev = stats::std_dev(values).filter(|&sd| sd > 0.0)?;
+            Some((mean - threshold * std_dev, mean + threshold * std_dev))
+        }
+    }
+}
+
+/// Zwraca wartości odstające w kolejności wejścia.
+pub fn detect(values: &[f64], methods: &[Method]) -> Vec<Outlier> {
+    let limits: Vec<(Method, (f64, f64))> = methods
+        .iter()
+        .filter_map(|&method| Some((method, bounds(values, method)?)))
+        .collect();
+
+    values
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &value)| {
+            let flagged: Vec<Method> = limits
+                .iter()
+                .filter(|(_, (low, high))| value < *low || value > *high)
+                .map(|&(method, _)| method)
+                .collect();
+            (!flagged.is_empty()).then(|| Outlier {
+                position: index + 1,
+                value,
+                methods: flagged,
+            })
+        })
+        .collect()
+}
+
+/// Wartości bez tych uznanych za odstające.
+pub fn without_outliers(values: &[f64], outliers: &[Outlier]) -> Vec<f64> {
+    let mut flagged = outliers.iter().map(|outlier| outlier.position).peekable();
+    values
+        .iter()
+        .enumerate()
+        .filter(|&(index, _)| {
+            if flagged.peek() == Some(&(index + 1)) {
+                flagged.next();
+                false
+            } else {
+                true
+            }
+        })
+        .map(|(_, &value)| value)
+        .collect()
+}
+
+/// Opisuje, dlaczego wartość została oznaczona, np. `IQR: poza [-2.50, 13.50]; z = 3.20`.
+fn describe_reasons(values: &[f64], outlier: &Outlier) -> String {
+    outlier
+        .methods
+        .iter()
+        .map(|&method| match method {
+            Method::Iqr(factor) => {
+                let (low, high) = iqr_fences(values, factor).unwrap_or((0.0, 0.0));
+                format!(
+                    "IQR: poza [{}, {}]",
+                    format_number(low),
+                    format_number(high)
+                )
+            }
+            Method::ZScore(_) => {
+                let z = z_score(values, outlier.value).unwrap_or(0.0);
+                format!("z = {z:.2}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Buduje raport: listę wartości odstających z pozycjami i wybrane statystyki policzone
+/// bez nich.
+pub fn outlier_report(values: &[f64], methods: &[Method], statistics: &[Statistic]) -> Vec<String> {
+    let outliers = detect(values, methods);
+    if outliers.is_empty() {
+        return vec!["Wartości odstające: brak".to_string()];
+    }
+
+    let mut lines = vec![format!("Wartości odstające ({}):", outliers.len())];
+    for outlier in &outliers {
+        lines.push(format!(
+            "  pozycja {}: {} ({})",
+            outlier.position,
+            format_number(outlier.value),
+            describe_reasons(values, outlier)
+        ));
+    }
+
+    let kept = without_outliers(values, &outliers);
+    lines.push("Bez wartości odstających:".to_string());
+    lines.extend(
+        statistics
+            .iter()
+            .flat_map(|&statistic| stats::render_statistic(&kept, statistic))
+            .map(|line| format!("  {line}")),
+    );
+    lines
+}
@@ -0,0 +1,323 @@
+//! Statystyki opisowe: średnia, mediana, dominanty, wariancja, kwartyle, percentyle
+//! i histogram kubełkowy, a także wybór statystyk wypisywanych w raporcie.
+use std::str::FromStr;
+
+/// Domyślna liczba kubełków histogramu.
+pub const DEFAULT_BUCKETS: usize = 10;
+
+/// Najdłuższy słupek histogramu w znakach.
+const BAR_WIDTH: usize = 40;
+
+/// Pojedyncza pozycja raportu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Statistic {
+    /// Lista liczb wraz z ich liczbą (`Liczby (n): ...`).
+    Numbers,
+    Min,
+    Max,
+    Sum,
+    Mean,
+    Median,
+    /// Wszystkie wartości o największej liczbie wystąpień.
+    Modes,
+    /// Wariancja populacji.
+    Variance,
+    /// Odchylenie standardowe populacji.
+    StdDev,
+    /// Pierwszy, drugi i trzeci kwartyl.
+    Quartiles,
+    /// Percentyl z zakresu `0..=100`.
+    Percentile(f64),
+    /// Histogram z podaną liczbą kubełków.
+    Histogram(usize),
+}
+
+impl Statistic {
+    /// Statystyki z podstawowego raportu.
+    pub const BASIC: [Statistic; 4] = [
+        Statistic::Numbers,
+        Statistic::Min,
+        Statistic::Max,
+        Statistic::Sum,
+    ];
+
+    /// Wszystkie statystyki w kolejności raportu `wszystko`.
+    pub fn all() -> Vec<Statistic> {
+        vec![
+            Statistic::Numbers,
+            Statistic::Min,
+            Statistic::Max,
+            Statistic::Sum,
+            Statistic::Mean,
+            Statistic::Median,
+            Statistic::Modes,
+            Statistic::Variance,
+            Statistic::StdDev,
+            Statistic::Quartiles,
+            Statistic::Histogram(DEFAULT_BUCKETS),
+        ]
+    }
+}
+
+impl FromStr for Statistic {
+    type Err = String;
+
+    /// Nazwy: `liczby`, `min`, `max`, `suma`, `srednia`, `mediana`, `dominanta`, `wariancja`,
+    /// `odchylenie`, `kwartyle`, `p<N>` (np. `p90`, `p99.9`) i `histogram[=kubełki]`.
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let name = raw.trim().to_lowercase();
+        let statistic = match name.as_str() {
+            "liczby" => Statistic::Numbers,
+            "min" => Statistic::Min,
+            "max" => Statistic::Max,
+            "suma" => Statistic::Sum,
+            "srednia" | "średnia" => Statistic::Mean,
+            "mediana" => Statistic::Median,
+            "dominanta" => Statistic::Modes,
+            "wariancja" => Statistic::Variance,
+            "odchylenie" => Statistic::StdDev,
+            "kwartyle" => Statistic::Quartiles,
+            "histogram" => Statistic::Histogram(DEFAULT_BUCKETS),
+            _ => {
+                if let Some(buckets) = name.strip_prefix("histogram=") {
+                    match buckets.parse::<usize>() {
+                        Ok(n) if n >= 1 => Statistic::Histogram(n),
+                        _ => return Err(format!("Niepoprawna liczba kubełków: {buckets}")),
+                    }
+                } else if let Some(rank) = name.strip_prefix('p') {
+                    match rank.parse::<f64>() {
+                        Ok(p) if (0.0..=100.0).contains(&p) => Statistic::Percentile(p),
+                        _ => return Err(format!("Niepoprawny percentyl: {}", raw.trim())),
+                    }
+                } else {
+                    return Err(format!("Nieznana statystyka: {}", raw.trim()));
+                }
+            }
+        };
+        Ok(statistic)
+    }
+}
+
+/// Parsuje listę statystyk rozdzieloną przecinkami; `wszystko` oznacza [`Statistic::all`].
+pub fn parse_statistics(list: &str) -> Result<Vec<Statistic>, String> {
+    let mut statistics = Vec::new();
+    for name in list.split(',').filter(|name| !name.trim().is_empty()) {
+        if name.trim() == "wszystko" {
+            statistics.extend(Statistic::all());
+        } else {
+            statistics.push(name.parse()?);
+        }
+    }
+    if statistics.is_empty() {
+        return Err("Nie wybrano żadnej statystyki.".to_string());
+    }
+    Ok(statistics)
+}
+
+/// Formatuje liczbę: wartości całkowite bez części ułamkowej, pozostałe z dwoma miejscami.
+pub fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+/// Średnia arytmetyczna; `None` dla pustych danych.
+pub fn mean(values: &[f64]) -> Option<f64> {
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Wariancja populacji (średni kwadrat odchylenia od średniej).
+pub fn variance(values: &[f64]) -> Option<f64> {
+    let mean = mean(values)?;
+    Some(values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64)
+}
+
+/// Odchylenie standardowe populacji.
+pub fn std_dev(values: &[f64]) -> Option<f64> {
+    variance(values).map(f64::sqrt)
+}
+
+fn sorted(values: &[f64]) -> Vec<f64> {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+/// Percentyl `p` (`0..=100`) z interpolacją liniową między sąsiednimi wartościami
+/// posortowanych danych (ta sama metoda co domyślna w arkuszach kalkulacyjnych).
+pub fn percentile(values: &[f64], p: f64) -> Option<f64> {
+    percentile_sorted(&sorted(values), p)
+}
+
+fn percentile_sorted(sorted: &[f64], p: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = p.clamp(0.0, 100.0) / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    Some(sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64))
+}
+
+/// Mediana (percentyl 50); dla parzystej liczby wartości średnia dwóch środkowych.
+pub fn median(values: &[f64]) -> Option<f64> {
+    percentile(values, 50.0)
+}
+
+/// Kwartyle `(Q1, Q2, Q3)`.
+pub fn quartiles(values: &[f64]) -> Option<(f64, f64, f64)> {
+    let sorted = sorted(values);
+    Some((
+        percentile_sorted(&sorted, 25.0)?,
+        percentile_sorted(&sorted, 50.0)?,
+        percentile_sorted(&sorted, 75.0)?,
+    ))
+}
+
+/// Wartości występujące najczęściej (rosnąco) i liczba ich wystąpień.
+pub fn modes(values: &[f64]) -> (Vec<f64>, usize) {
+    let sorted = sorted(values);
+    let mut runs: Vec<(f64, usize)> = Vec::new();
+    for value in sorted {
+        match runs.last_mut() {
+            Some((last, count)) if *last == value => *count += 1,
+            _ => runs.push((value, 1)),
+        }
+    }
+    let best = runs.iter().map(|&(_, count)| count).max().unwrap_or(0);
+    let modes = runs
+        .into_iter()
+        .filter(|&(_, count)| count == best)
+        .map(|(value, _)| value)
+        .collect();
+    (modes, best)
+}
+
+/// Kubełek histogramu: przedział `[start, end)` (ostatni domknięty) i liczba wartości.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bucket {
+    pub start: f64,
+    pub end: f64,
+    pub count: usize,
+}
+
+/// Dzieli zakres `[min, max]` na `buckets` równych przedziałów i zlicza wartości.
+/// Dla danych całkowitych szerokość kubełka jest zaokrąglana w górę do liczby całkowitej.
+pub fn histogram(values: &[f64], buckets: usize) -> Vec<Bucket> {
+    let buckets = buckets.max(1);
+    let (Some(min), Some(max)) = (
+        values.iter().copied().min_by(f64::total_cmp),
+        values.iter().copied().max_by(f64::total_cmp),
+    ) else {
+        return Vec::new();
+    };
+
+    let integers = values.iter().all(|v| v.fract() == 0.0);
+    let mut width = (max - min) / buckets as f64;
+    if integers {
+        width = width.ceil();
+    }
+    if width <= 0.0 {
+        width = 1.0;
+    }
+
+    // Przy zaokrąglonej szerokości ostatnie kubełki mogłyby leżeć całkiem za maksimum.
+    let buckets = (((max - min) / width).floor() as usize + 1).min(buckets);
+    let mut result: Vec<Bucket> = (0..buckets)
+        .map(|index| Bucket {
+            start: min + width * index as f64,
+            end: min + width * (index + 1) as f64,
+            count: 0,
+        })
+        .collect();
+    for value in values {
+        let index = (((value - min) / width) as usize).min(buckets - 1);
+        result[index].count += 1;
+    }
+    result
+}
+
+/// Formatuje wybraną statystykę jako linie raportu.
+pub fn render_statistic(values: &[f64], statistic: Statistic) -> Vec<String> {
+    let number = |value: Option<f64>| value.map(format_number).unwrap_or_else(|| "-".into());
+    match statistic {
+        Statistic::Numbers => {
+            let joined: Vec<String> = values.iter().map(|&v| format_number(v)).collect();
+            vec![format!("Liczby ({}): {}", values.len(), joined.join(" "))]
+        }
+        Statistic::Min => vec![format!(
+            "Minimum: {}",
+            number(values.iter().copied().min_by(f64::total_cmp))
+        )],
+        Statistic::Max => vec![format!(
+            "Maksimum: {}",
+            number(values.iter().copied().max_by(f64::total_cmp))
+        )],
+        Statistic::Sum => vec![format!("Suma: {}", format_number(values.iter().sum()))],
+        Statistic::Mean => vec![format!("Średnia: {}", number(mean(values)))],
+        Statistic::Median => vec![format!("Mediana: {}", number(median(values)))],
+        Statistic::Modes => {
+            let (modes, count) = modes(values);
+            if count <= 1 && values.len() > 1 {
+                vec!["Dominanta: brak (każda wartość występuje raz)".to_string()]
+            } else {
+                let joined: Vec<String> = modes.into_iter().map(format_number).collect();
+                vec![format!("Dominanta: {} ({count} razy)", joined.join(", "))]
+            }
+        }
+        Statistic::Variance => vec![format!("Wariancja: {}", number(variance(values)))],
+        Statistic::StdDev => vec![format!(
+            "Odchylenie standardowe: {}",
+            number(std_dev(values))
+        )],
+        Statistic::Quartiles => match quartiles(values) {
+            Some((q1, q2, q3)) => vec![format!(
+                "Kwartyle: Q1 {}, Q2 {}, Q3 {}",
+                format_number(q1),
+                format_number(q2),
+                format_number(q3)
+            )],
+            None => vec!["Kwartyle: -".to_string()],
+        },
+        Statistic::Percentile(p) => vec![format!(
+            "Percentyl {}: {}",
+            format_number(p),
+            number(percentile(values, p))
+        )],
+        Statistic::Histogram(buckets) => render_histogram(&histogram(values, buckets)),
+    }
+}
+
+fn render_histogram(buckets: &[Bucket]) -> Vec<String> {
+    let labels: Vec<String> = buckets
+        .iter()
+        .enumerate()
+        .map(|(index, bucket)| {
+            let close = if index + 1 == buckets.len() { ']' } else { ')' };
+            format!(
+                "[{}, {}{close}",
+                format_number(bucket.start),
+                format_number(bucket.end)
+            )
+        })
+        .collect();
+    let label_width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    let max = buckets.iter().map(|b| b.count).max().unwrap_or(0);
+
+    let mut lines = vec!["Histogram:".to_string()];
+    for (label, bucket) in labels.iter().zip(buckets) {
+        let bar = if max == 0 {
+            0
+        } else {
+            (bucket.count * BAR_WIDTH).div_ceil(max)
+        };
+        lines.push(format!(
+            "  {label:<label_width$} | {} {}",
+            "#".repeat(bar),
+            bucket.count
+        ));
+    }
+    lines
+}
@@ -0,0 +1,362 @@
+//! Strumieniowa analiza dużych danych: liczby są czytane kawałkami z dowolnego źródła
+//! (`io::Read`), a podsumowanie powstaje w jednym przebiegu bez przechowywania wartości.
+//!
+//! Suma jest liczona dokładnie w arytmetyce 256-bitowej, więc nie przepełnia się nawet
+//! dla wartości z pełnego zakresu `i128`.
+use std::fmt;
+use std::io::{BufRead, BufReader, Read};
+use std::str::FromStr;
+
+use crate::syntax::{self, is_separator};
+
+/// Zakres akceptowanych liczb całkowitych.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntWidth {
+    I32,
+    #[default]
+    I64,
+    I128,
+}
+
+impl IntWidth {
+    /// Parsuje token jako liczbę z zakresu typu.
+    pub fn parse(self, token: &str) -> Option<i128> {
+        match self {
+            IntWidth::I32 => token.parse::<i32>().ok().map(i128::from),
+            IntWidth::I64 => token.parse::<i64>().ok().map(i128::from),
+            IntWidth::I128 => token.parse::<i128>().ok(),
+        }
+    }
+
+    /// Sprawdza, czy wartość mieści się w zakresie typu.
+    pub fn contains(self, value: i128) -> bool {
+        match self {
+            IntWidth::I32 => i32::try_from(value).is_ok(),
+            IntWidth::I64 => i64::try_from(value).is_ok(),
+            IntWidth::I128 => true,
+        }
+    }
+
+    /// Następny szerszy typ (do podpowiedzi przy przepełnieniu).
+    pub fn wider(self) -> Option<IntWidth> {
+        match self {
+            IntWidth::I32 => Some(IntWidth::I64),
+            IntWidth::I64 => Some(IntWidth::I128),
+            IntWidth::I128 => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            IntWidth::I32 => "i32",
+            IntWidth::I64 => "i64",
+            IntWidth::I128 => "i128",
+        }
+    }
+}
+
+impl FromStr for IntWidth {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "i32" => Ok(IntWidth::I32),
+            "i64" => Ok(IntWidth::I64),
+            "i128" => Ok(IntWidth::I128),
+            _ => Err(format!("Nieznany typ liczb: {}", raw.trim())),
+        }
+    }
+}
+
+/// Dokładna suma: 256-bitowa liczba w kodzie uzupełnień do dwóch (cztery słowa, od najmłodszego).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExactSum {
+    limbs: [u64; 4],
+}
+
+impl ExactSum {
+    pub fn add(&mut self, value: i128) {
+        let extension = if value < 0 { u64::MAX } else { 0 };
+        let bits = value as u128;
+        let addend = [bits as u64, (bits >> 64) as u64, extension, extension];
+        let mut carry = false;
+        for (limb, &add) in self.limbs.iter_mut().zip(&addend) {
+            let (partial, first) = limb.overflowing_add(add);
+            let (result, second) = partial.overflowing_add(carry as u64);
+            *limb = result;
+            carry = first || second;
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.limbs[3] >> 63 == 1
+    }
+
+    /// Wartość bezwzględna jako cztery słowa.
+    fn magnitude(&self) -> [u64; 4] {
+        if !self.is_negative() {
+            return self.limbs;
+        }
+        // -x = !x + 1
+        let mut limbs = self.limbs.map(|limb| !limb);
+        for limb in limbs.iter_mut() {
+            let (result, overflow) = limb.overflowing_add(1);
+            *limb = result;
+            if !overflow {
+                break;
+            }
+        }
+        limbs
+    }
+
+    /// Wartość jako `i128`, jeśli się mieści.
+    pub fn to_i128(&self) -> Option<i128> {
+        let low = (self.limbs[0] as u128 | (self.limbs[1] as u128) << 64) as i128;
+        let extension = if low < 0 { u64::MAX } else { 0 };
+        (self.limbs[2] == extension && self.limbs[3] == extension).then_some(low)
+    }
+
+    /// Przybliżona wartość zmiennoprzecinkowa (np. do liczenia średniej).
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self
+            .magnitude()
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &limb| acc * 2f64.powi(64) + limb as f64);
+        if self.is_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl fmt::Display for ExactSum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(value) = self.to_i128() {
+            return write!(f, "{value}");
+        }
+
+        // Dzielenie pisemne przez 10^19 na słowach 64-bitowych.
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut limbs = self.magnitude();
+        let mut chunks = Vec::new();
+        while limbs.iter().any(|&limb| limb != 0) {
+            let mut remainder: u128 = 0;
+            for limb in limbs.iter_mut().rev() {
+                let current = (remainder << 64) | *limb as u128;
+                *limb = (current / CHUNK as u128) as u64;
+                remainder = current % CHUNK as u128;
+            }
+            chunks.push(remainder as u64);
+        }
+
+        let sign = if self.is_negative() { "-" } else { "" };
+        let mut text = format!("{sign}{}", chunks.pop().unwrap_or(0));
+        for chunk in chunks.iter().rev() {
+            text.push_str(&format!("{chunk:019}"));
+        }
+        write!(f, "{text}")
+    }
+}
+
+/// Podsumowanie liczone w jednym przebiegu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StreamSummary {
+    pub count: u64,
+    pub min: Option<i128>,
+    pub max: Option<i128>,
+    pub sum: ExactSum,
+}
+
+impl StreamSummary {
+    pub fn push(&mut self, value: i128) {
+        self.count += 1;
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+        self.sum.add(value);
+    }
+
+    /// Średnia w przybliżeniu zmiennoprzecinkowym.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum.to_f64() / self.count as f64)
+    }
+}
+
+/// Najdłuższy token (w znakach) czytany przez [`Tokens`]; dłuższy kończy analizę błędem,
+/// zanim trafi w całości do pamięci.
+pub const MAX_TOKEN_LEN: usize = 1024;
+
+/// Token wejścia wraz z położeniem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub text: String,
+    /// Numer tokenu liczony od 1.
+    pub index: u64,
+    /// Numer linii liczony od 1.
+    pub line: u64,
+    /// Numer kolumny (znaku w linii) liczony od 1.
+    pub column: u64,
+}
+
+impl Token {
+    /// Opis położenia używany w komunikatach błędów.
+    pub fn position(&self) -> String {
+        format!(
+            "token {}, linia {}, kolumna {}",
+            self.index, self.line, self.column
+        )
+    }
+}
+
+/// Iterator tokenów rozdzielonych białymi znakami, przecinkami lub średnikami,
+/// czytający dane kawałkami.
+/// Token może przechodzić przez granicę bufora, a długość linii nie ma znaczenia;
+/// sam token może mieć najwyżej [`MAX_TOKEN_LEN`] znaków.
+pub struct Tokens<R> {
+    reader: BufReader<R>,
+    index: u64,
+    line: u64,
+    column: u64,
+    /// Niepełny znak UTF-8 z końca poprzedniego kawałka.
+    pending: Vec<u8>,
+    failed: bool,
+}
+
+impl<R: Read> Tokens<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            index: 0,
+            line: 1,
+            column: 0,
+            pending: Vec::new(),
+            failed: false,
+        }
+    }
+
+    /// Czyta kolejny znak, obsługując znaki UTF-8 rozdzielone między kawałki.
+    fn next_char(&mut self) -> Result<Option<char>, String> {
+        loop {
+            let buffer = self
+                .reader
+                .fill_buf()
+                .map_err(|err| format!("Nie udało się odczytać danych: {err}"))?;
+            if buffer.is_empty() {
+                if self.pending.is_empty() {
+                    return Ok(None);
+                }
+                return Err("Niepoprawne kodowanie UTF-8 na końcu danych.".to_string());
+            }
+
+            let byte = buffer[0];
+            self.reader.consume(1);
+            self.pending.push(byte);
+            match std::str::from_utf8(&self.pending) {
+                Ok(text) => {
+                    let ch = text.chars().next();
+                    self.pending.clear();
+                    return Ok(ch);
+                }
+                Err(err) if err.error_len().is_none() => continue,
+                Err(_) => {
+                    return Err(format!(
+                        "Niepoprawne kodowanie UTF-8 w linii {}.",
+                        self.line
+                    ))
+                }
+            }
+        }
+    }
+
+    fn read_token(&mut self) -> Result<Option<Token>, String> {
+        let mut text = String::new();
+        let mut length = 0;
+        let mut start = (0, 0);
+        while let Some(ch) = self.next_char()? {
+            self.column += 1;
+            if is_separator(ch) {
+                if ch == '\n' {
+                    self.line += 1;
+                    self.column = 0;
+                }
+                if !text.is_empty() {
+                    break;
+                }
+                continue;
+            }
+            if text.is_empty() {
+                start = (self.line, self.column);
+            }
+            length += 1;
+            if length > MAX_TOKEN_LEN {
+                return Err(format!(
+                    "Token dłuższy niż {MAX_TOKEN_LEN} znaków (token {}, linia {}, kolumna {}).",
+                    self.index + 1,
+                    start.0,
+                    start.1
+                ));
+            }
+            text.push(ch);
+        }
+
+        if text.is_empty() {
+            return Ok(None);
+        }
+        self.index += 1;
+        Ok(Some(Token {
+            text,
+            index: self.index,
+            line: start.0,
+            column: start.1,
+        }))
+    }
+}
+
+impl<R: Read> Iterator for Tokens<R> {
+    type Item = Result<Token, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let token = self.read_token().transpose();
+        self.failed = matches!(token, Some(Err(_)));
+        token
+    }
+}
+
+/// Analizuje liczby z dowolnego źródła w jednym przebiegu, bez zapamiętywania wartości.
+/// Tokeny mogą być literałami w dowolnym systemie (`0x1F`) lub zakresami (`1..=10`);
+/// błąd wskazuje pierwszy token, którego nie dało się odczytać jako liczby z zakresu `width`.
+pub fn analyze_reader<R: Read>(reader: R, width: IntWidth) -> Result<StreamSummary, String> {
+    let mut summary = StreamSummary::default();
+    for token in Tokens::new(reader) {
+        let token = token?;
+        let literal = syntax::parse_integer_literal(&token.text, width)
+            .map_err(|problem| problem.at(&token.text, token.position()).to_string())?;
+        for value in syntax::expand(literal) {
+            summary.push(value);
+        }
+    }
+
+    if summary.count == 0 {
+        return Err("Brak liczb do przeanalizowania.".to_string());
+    }
+    Ok(summary)
+}
+
+/// Buduje raport z podsumowania strumieniowego.
+pub fn describe_summary(summary: &StreamSummary) -> Vec<String> {
+    let value = |v: Option<i128>| v.map_or_else(|| "-".to_string(), |v| v.to_string());
+    let mean = summary
+        .mean()
+        .map_or_else(|| "-".to_string(), |mean| format!("{mean:.2}"));
+    vec![
+        format!("Liczba wartości: {}", summary.count),
+        format!("Minimum: {}", value(summary.min)),
+        format!("Maksimum: {}", value(summary.max)),
+        format!("Suma: {}", summary.sum),
+        format!("Średnia: {mean}"),
+    ]
+}
@@ -0,0 +1,324 @@
+//! Składnia liczb na wejściu: literały dziesiętne, szesnastkowe (`0x1F`), dwójkowe (`0b101`)
+//! i ósemkowe (`0o17`), zakresy domknięte (`1..=10`), separatory `,` i `;` oraz opcjonalnie
+//! liczby rzeczywiste. Błędy są zgłaszane osobno dla każdego tokenu, razem z podpowiedzią.
+use std::fmt;
+
+use crate::stream::IntWidth;
+
+/// Największa liczba wartości, na którą może się rozwinąć jeden zakres.
+pub const MAX_RANGE_LEN: u128 = 10_000_000;
+
+/// Największa łączna liczba wartości (po rozwinięciu wszystkich zakresów), jaką
+/// `parse_integers` i `parse_floats` zapisują w pamięci. Tryb `--strumien` nie ma tego limitu.
+pub const MAX_TOTAL_VALUES: u128 = 10_000_000;
+
+/// Sprawdza, czy znak rozdziela tokeny (biały znak, przecinek lub średnik).
+pub fn is_separator(ch: char) -> bool {
+    ch.is_whitespace() || ch == ',' || ch == ';'
+}
+
+/// Dzieli wejście na tokeny.
+pub fn split_tokens(input: &str) -> impl Iterator<Item = &str> {
+    input.split(is_separator).filter(|token| !token.is_empty())
+}
+
+/// Błąd pojedynczego tokenu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenError {
+    pub token: String,
+    /// Położenie tokenu, np. `token 3` albo `token 4, linia 2, kolumna 3`.
+    pub position: String,
+    pub message: String,
+    /// Podpowiedź, np. `czy chodziło o 0x1F?`.
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for TokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Niepoprawna liczba: {} ({}: {}",
+            self.token, self.position, self.message
+        )?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, "; {suggestion}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Opis problemu z tokenem, zanim zostanie mu przypisane położenie.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub message: String,
+    pub suggestion: Option<String>,
+}
+
+impl Problem {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            suggestion: None,
+        }
+    }
+
+    fn suggest(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Dołącza token i jego położenie.
+    pub fn at(self, token: &str, position: String) -> TokenError {
+        TokenError {
+            token: token.to_string(),
+            position,
+            message: self.message,
+            suggestion: self.suggestion,
+        }
+    }
+}
+
+/// Pojedyncza liczba całkowita albo zakres domknięty `start..=end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Literal {
+    Single(i128),
+    Range(i128, i128),
+}
+
+/// Zamienia znaki często mylone z cyframi (`O` → `0`, `l`/`I` → `1`).
+fn fix_lookalikes(text: &str) -> String {
+    text.chars()
+        .map(|ch| match ch {
+            'O' | 'o' => '0',
+            'l' | 'I' => '1',
+            other => other,
+        })
+        .collect()
+}
+
+/// Parsuje liczbę całkowitą z opcjonalnym znakiem i prefiksem podstawy.
+fn parse_int(text: &str, width: IntWidth) -> Result<i128, Problem> {
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let lower = unsigned.to_ascii_lowercase();
+    let (radix, digits) = match lower.get(..2) {
+        Some("0x") => (16, &unsigned[2..]),
+        Some("0b") => (2, &unsigned[2..]),
+        Some("0o") => (8, &unsigned[2..]),
+        _ => (10, unsigned),
+    };
+    if digits.is_empty() {
+        return Err(Problem::new("brak cyfr"));
+    }
+
+    let magnitude = match digits.chars().find(|c| !c.is_digit(radix)) {
+        None => u128::from_str_radix(digits, radix).ok(),
+        Some(bad) => return Err(digit_problem(text, digits, bad, radix, width)),
+    };
+    let value = magnitude.and_then(|m| {
+        if negative {
+            0i128.checked_sub_unsigned(m)
+        } else {
+            i128::try_from(m).ok()
+        }
+    });
+
+    match value {
+        Some(value) if width.contains(value) => Ok(value),
+        _ => {
+            let problem = Problem::new(format!("wartość poza zakresem {}", width.name()));
+            Err(match width.wider() {
+                Some(wider) => problem.suggest(format!("użyj opcji --typ {}", wider.name())),
+                None => problem,
+            })
+        }
+    }
+}
+
+/// Opisuje niedozwolony znak w liczbie i szuka poprawki.
+fn digit_problem(text: &str, digits: &str, bad: char, radix: u32, width: IntWidth) -> Problem {
+    if radix == 10 && bad == '.' {
+        return Problem::new("liczba z częścią ułamkową")
+            .suggest("użyj opcji --rzeczywiste, aby wczytać liczby rzeczywiste");
+    }
+
+    let fixed = fix_lookalikes(text);
+    if fixed != text && parse_int(&fixed, width).is_ok() {
+        return Problem::new(format!("niedozwolony znak '{bad}'"))
+            .suggest(format!("czy chodziło o {fixed}?"));
+    }
+    let hex_like = digits.chars().all(|c| c.is_ascii_hexdigit());
+    if radix == 10 && hex_like && digits.chars().any(|c| c.is_ascii_digit()) {
+        let sign = if text.starts_with('-') { "-" } else { "" };
+        return Problem::new(format!("niedozwolony znak '{bad}'"))
+            .suggest(format!("czy chodziło o {sign}0x{digits}?"));
+    }
+    match radix {
+        2 => Problem::new(format!("cyfra '{bad}' nie występuje w systemie dwójkowym")),
+        8 => Problem::new(format!("cyfra '{bad}' nie występuje w systemie ósemkowym")),
+        16 => Problem::new(format!(
+            "znak '{bad}' nie występuje w systemie szesnastkowym"
+        )),
+        _ => Problem::new(format!("niedozwolony znak '{bad}'")),
+    }
+}
+
+/// Parsuje token w trybie całkowitym: liczbę albo zakres `a..=b`.
+pub fn parse_integer_literal(token: &str, width: IntWidth) -> Result<Literal, Problem> {
+    let Some((start, end)) = token.split_once("..") else {
+        return parse_int(token, width).map(Literal::Single);
+    };
+    let Some(end) = end.strip_prefix('=') else {
+        return Err(Problem::new("zakres musi być domknięty")
+            .suggest(format!("czy chodziło o {start}..={end}?")));
+    };
+
+    let (start_value, end_value) = (parse_int(start, width)?, parse_int(end, width)?);
+    if start_value > end_value {
+        return Err(
+            Problem::new("zakres malejący").suggest(format!("czy chodziło o {end}..={start}?"))
+        );
+    }
+    if end_value.abs_diff(start_value) >= MAX_RANGE_LEN {
+        return Err(Problem::new(format!(
+            "zakres ma więcej niż {MAX_RANGE_LEN} wartości"
+        )));
+    }
+    Ok(Literal::Range(start_value, end_value))
+}
+
+/// Token w trybie rzeczywistym: literał całkowity (liczba lub zakres) albo liczba rzeczywista.
+enum FloatLiteral {
+    Integer(Literal),
+    Real(f64),
+}
+
+/// Parsuje token w trybie rzeczywistym: liczbę całkowitą w dowolnym systemie, zakres
+/// całkowity albo liczbę zmiennoprzecinkową (`2.5`, `-1e3`).
+fn parse_float_literal(token: &str) -> Result<FloatLiteral, Problem> {
+    match parse_integer_literal(token, IntWidth::I128) {
+        Ok(literal) => return Ok(FloatLiteral::Integer(literal)),
+        Err(problem) if token.contains("..") => return Err(problem),
+        Err(_) => {}
+    }
+
+    match token.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(FloatLiteral::Real(value)),
+        Ok(_) => Err(Problem::new("wartość nieskończona lub nieokreślona")),
+        Err(_) => {
+            let fixed = fix_lookalikes(token);
+            let problem = Problem::new("niepoprawna liczba rzeczywista");
+            Err(match fixed.parse::<f64>() {
+                Ok(value) if value.is_finite() => {
+                    problem.suggest(format!("czy chodziło o {fixed}?"))
+                }
+                _ => problem,
+            })
+        }
+    }
+}
+
+/// Rozwija literał do kolejnych wartości.
+pub fn expand(literal: Literal) -> impl Iterator<Item = i128> {
+    let (start, end) = match literal {
+        Literal::Single(value) => (value, value),
+        Literal::Range(start, end) => (start, end),
+    };
+    start..=end
+}
+
+/// Liczba wartości, na którą rozwija się literał (dla zakresu `i128::MIN..=i128::MAX`
+/// obcinana do `u128::MAX`).
+pub fn literal_len(literal: Literal) -> u128 {
+    match literal {
+        Literal::Single(_) => 1,
+        Literal::Range(start, end) => end.abs_diff(start).saturating_add(1),
+    }
+}
+
+/// Dolicza `count` wartości do łącznej liczby `total`. Problem jest zgłaszany tylko dla
+/// tokenu, który pierwszy przekracza [`MAX_TOTAL_VALUES`], żeby nie powtarzać go dla
+/// każdego kolejnego tokenu.
+fn count_values(total: &mut u128, count: u128) -> Result<(), Problem> {
+    let before = *total;
+    *total = before.saturating_add(count);
+    if before <= MAX_TOTAL_VALUES && *total > MAX_TOTAL_VALUES {
+        return Err(Problem::new(format!(
+            "łącznie więcej niż {MAX_TOTAL_VALUES} wartości"
+        )));
+    }
+    Ok(())
+}
+
+/// Parsuje liczby całkowite z zakresu `width`, rozwijając zakresy.
+/// Zwraca wszystkie błędne tokeny naraz, a nie tylko pierwszy. Wartości są rozwijane
+/// dopiero wtedy, gdy wszystkie tokeny są poprawne i razem nie przekraczają
+/// [`MAX_TOTAL_VALUES`].
+pub fn parse_integers(input: &str, width: IntWidth) -> Result<Vec<i128>, Vec<TokenError>> {
+    let mut literals = Vec::new();
+    let mut errors = Vec::new();
+    let mut total = 0;
+    for (index, token) in split_tokens(input).enumerate() {
+        let checked = parse_integer_literal(token, width).and_then(|literal| {
+            count_values(&mut total, literal_len(literal))
+                .map_err(|problem| problem.suggest("użyj opcji --strumien"))?;
+            Ok(literal)
+        });
+        match checked {
+            Ok(literal) => literals.push(literal),
+            Err(problem) => errors.push(problem.at(token, format!("token {}", index + 1))),
+        }
+    }
+    if errors.is_empty() {
+        Ok(literals.into_iter().flat_map(expand).collect())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Parsuje liczby rzeczywiste (oraz całkowite w dowolnym systemie i zakresy całkowite)
+/// z tym samym łącznym limitem wartości co `parse_integers`.
+pub fn parse_floats(input: &str) -> Result<Vec<f64>, Vec<TokenError>> {
+    let mut literals = Vec::new();
+    let mut errors = Vec::new();
+    let mut total = 0;
+    for (index, token) in split_tokens(input).enumerate() {
+        let checked = parse_float_literal(token).and_then(|literal| {
+            let count = match literal {
+                FloatLiteral::Integer(literal) => literal_len(literal),
+                FloatLiteral::Real(_) => 1,
+            };
+            count_values(&mut total, count)?;
+            Ok(literal)
+        });
+        match checked {
+            Ok(literal) => literals.push(literal),
+            Err(problem) => errors.push(problem.at(token, format!("token {}", index + 1))),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut values = Vec::new();
+    for literal in literals {
+        match literal {
+            FloatLiteral::Integer(literal) => {
+                values.extend(expand(literal).map(|value| value as f64))
+            }
+            FloatLiteral::Real(value) => values.push(value),
+        }
+    }
+    Ok(values)
+}
+
+/// Łączy błędy tokenów w jeden komunikat (po jednej linii na token).
+pub fn format_errors(errors: &[TokenError]) -> String {
+    errors
+        .iter()
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,62 @@
+use a_ex_5::stats::{
+    histogram, mean, median, modes, parse_statistics, percentile, quartiles, std_dev, Bucket,
+    Statistic,
+};
+use a_ex_5::{describe_numbers, describe_selected, parse_numbers, run_analysis, run_analysis_with};
+
+#[test]
+fn basic_report_is_unchanged() {
+    assert_eq!(run_analysis("10   -5\t3\n").unwrap(), vec![
+        "Liczby (3): 10 -5 3",
+        "Minimum: -5",
+        "Maksimum: 10",
+        "Suma: 8",
+    ]);
+    assert_eq!(describe_selected(&[10, -5, 3], &Statistic::BASIC), describe_numbers(&[10, -5, 3]));
+    assert_eq!(parse_numbers("  \n").unwrap_err(), "Brak liczb do przeanalizowania.");
+    assert_eq!(parse_numbers("1 x").unwrap_err(), "Niepoprawna liczba: x");
+}
+
+#[test]
+fn descriptive_statistics() {
+    let values = [10.0, -5.0, 3.0, 3.0];
+    assert_eq!(mean(&values), Some(2.75));
+    assert_eq!(median(&values), Some(3.0));
+    assert_eq!(modes(&values), (vec![3.0], 2));
+    assert_eq!(quartiles(&values), Some((1.0, 3.0, 4.75)));
+    assert!((percentile(&values, 90.0).unwrap() - 7.9).abs() < 1e-9);
+    assert_eq!(std_dev(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]), Some(2.0));
+    assert_eq!(mean(&[]), None);
+    assert_eq!(median(&[]), None);
+}
+
+#[test]
+fn histogram_buckets_cover_the_range() {
+    assert_eq!(histogram(&[1.0, 2.0, 9.0, 10.0], 3), vec![
+        Bucket { start: 1.0, end: 4.0, count: 2 },
+        Bucket { start: 4.0, end: 7.0, count: 0 },
+        Bucket { start: 7.0, end: 10.0, count: 2 },
+    ]);
+    assert_eq!(histogram(&[5.0, 5.0], 4), vec![Bucket { start: 5.0, end: 6.0, count: 2 }]);
+    assert!(histogram(&[], 4).is_empty());
+}
+
+#[test]
+fn selected_statistics_in_requested_order() {
+    let statistics = parse_statistics("srednia,mediana,dominanta,p90").unwrap();
+    assert_eq!(run_analysis_with("10 -5 3 3", &statistics).unwrap(), vec![
+        "Średnia: 2.75",
+        "Mediana: 3",
+        "Dominanta: 3 (2 razy)",
+        "Percentyl 90: 7.90",
+    ]);
+    assert_eq!(
+        run_analysis_with("1 2", &[Statistic::Modes]).unwrap(),
+        vec!["Dominanta: brak (każda wartość występuje raz)"]
+    );
+    assert_eq!(parse_statistics("wszystko").unwrap(), Statistic::all());
+    assert_eq!(parse_statistics(" , ").unwrap_err(), "Nie wybrano żadnej statystyki.");
+    assert_eq!(parse_statistics("p101").unwrap_err(), "Niepoprawny percentyl: p101");
+    assert_eq!(parse_statistics("histogram=0").unwrap_err(), "Niepoprawna liczba kubełków: 0");
+    assert_eq!(parse_statistics("moda").unwrap_err(), "Nieznana statystyka: moda");
+}
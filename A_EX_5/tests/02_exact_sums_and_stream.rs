@@ -0,0 +1,105 @@
+use std::io::Read;
+
+use a_ex_5::stats::Statistic;
+use a_ex_5::stream::{analyze_reader, describe_summary, ExactSum, IntWidth, Tokens, MAX_TOKEN_LEN};
+use a_ex_5::{describe_selected, parse_args, run, summarize_numbers};
+
+/// Źródło oddające dane po jednym bajcie, żeby tokeny i znaki UTF-8 przechodziły
+/// przez granice kawałków.
+struct OneByte<'a>(&'a [u8]);
+
+impl Read for OneByte<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self.0.split_first() {
+            Some((&byte, rest)) if !buf.is_empty() => {
+                buf[0] = byte;
+                self.0 = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+#[test]
+fn summarize_numbers_sum_is_widened() {
+    assert_eq!(summarize_numbers(&[10, -5, 3]), (3, -5, 10, 8));
+    assert_eq!(summarize_numbers(&[i32::MAX, 1]), (2, 1, i32::MAX, 2_147_483_648));
+    assert_eq!(summarize_numbers(&[i32::MIN, -1]), (2, i32::MIN, -1, -2_147_483_649));
+}
+
+#[test]
+fn selected_sum_is_exact() {
+    assert_eq!(describe_selected(&[i32::MAX, 1], &[Statistic::Sum]), vec!["Suma: 2147483648"]);
+
+    let options = parse_args(["--typ", "i64", "--pokaz", "liczby,suma,max"]).unwrap();
+    let input = "9223372036854775807 9223372036854775807 -1\n";
+    assert_eq!(run(&options, input.as_bytes()).unwrap(), vec![
+        "Liczby (3): 9223372036854775807 9223372036854775807 -1",
+        "Suma: 18446744073709551613",
+        "Maksimum: 9223372036854775807",
+    ]);
+}
+
+#[test]
+fn exact_sum_spans_the_full_i128_range() {
+    let mut sum = ExactSum::default();
+    sum.add(i128::MAX);
+    sum.add(i128::MAX);
+    assert_eq!(sum.to_string(), "340282366920938463463374607431768211454");
+    assert_eq!(sum.to_i128(), None);
+    sum.add(i128::MIN);
+    sum.add(i128::MIN);
+    sum.add(i128::MIN);
+    assert_eq!(sum.to_string(), "-170141183460469231731687303715884105730");
+    assert!(sum.is_negative());
+}
+
+#[test]
+fn stream_reads_every_line_in_chunks() {
+    let summary = analyze_reader(OneByte("1, 2;3\n0x1F  1..=3\n".as_bytes()), IntWidth::I64).unwrap();
+    assert_eq!(describe_summary(&summary), vec![
+        "Liczba wartości: 7",
+        "Minimum: 1",
+        "Maksimum: 31",
+        "Suma: 43",
+        "Średnia: 6.14",
+    ]);
+    assert_eq!(analyze_reader(" \n".as_bytes(), IntWidth::I64).unwrap_err(), "Brak liczb do przeanalizowania.");
+}
+
+#[test]
+fn tokens_report_positions_and_encoding_errors() {
+    let tokens: Vec<String> = Tokens::new(OneByte("1 ż\n  x4".as_bytes()))
+        .map(|token| token.unwrap().position())
+        .collect();
+    assert_eq!(tokens, vec![
+        "token 1, linia 1, kolumna 1",
+        "token 2, linia 1, kolumna 3",
+        "token 3, linia 2, kolumna 3",
+    ]);
+    assert_eq!(
+        analyze_reader("1\n2 x4".as_bytes(), IntWidth::I64).unwrap_err(),
+        "Niepoprawna liczba: x4 (token 3, linia 2, kolumna 3: niedozwolony znak 'x')"
+    );
+    assert_eq!(
+        analyze_reader(&b"1\n\xff"[..], IntWidth::I64).unwrap_err(),
+        "Niepoprawne kodowanie UTF-8 w linii 2."
+    );
+    assert_eq!(
+        analyze_reader(&b"1 \xc5"[..], IntWidth::I64).unwrap_err(),
+        "Niepoprawne kodowanie UTF-8 na końcu danych."
+    );
+}
+
+#[test]
+fn tokens_longer_than_the_limit_are_rejected() {
+    let longest = format!("1 {}", "0".repeat(MAX_TOKEN_LEN));
+    assert_eq!(Tokens::new(longest.as_bytes()).count(), 2);
+
+    let too_long = format!("1 {}", "0".repeat(MAX_TOKEN_LEN + 1));
+    assert_eq!(
+        analyze_reader(OneByte(too_long.as_bytes()), IntWidth::I64).unwrap_err(),
+        format!("Token dłuższy niż {MAX_TOKEN_LEN} znaków (token 2, linia 1, kolumna 3).")
+    );
+}
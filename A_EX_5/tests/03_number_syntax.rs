@@ -0,0 +1,76 @@
+use a_ex_5::stream::IntWidth;
+use a_ex_5::syntax::{
+    expand, literal_len, parse_floats, parse_integer_literal, parse_integers, Literal,
+    MAX_RANGE_LEN, MAX_TOTAL_VALUES,
+};
+use a_ex_5::{parse_args, run};
+
+fn messages(input: &str, width: IntWidth) -> Vec<String> {
+    parse_integers(input, width).unwrap_err().iter().map(|error| error.to_string()).collect()
+}
+
+#[test]
+fn literals_in_every_base_and_ranges() {
+    assert_eq!(parse_integers("1,2;3 0x1F 0b101 -0o17 1..=4", IntWidth::I32).unwrap(), vec![
+        1, 2, 3, 31, 5, -15, 1, 2, 3, 4,
+    ]);
+    assert_eq!(parse_integer_literal("-2..=2", IntWidth::I32), Ok(Literal::Range(-2, 2)));
+    assert_eq!(expand(Literal::Range(-1, 1)).collect::<Vec<_>>(), vec![-1, 0, 1]);
+    assert_eq!(literal_len(Literal::Range(i128::MIN, i128::MAX)), u128::MAX);
+}
+
+#[test]
+fn every_bad_token_is_reported_with_a_hint() {
+    assert_eq!(messages("12 1O 1F 1..4 5..=1", IntWidth::I32), vec![
+        "Niepoprawna liczba: 1O (token 2: niedozwolony znak 'O'; czy chodziło o 10?)",
+        "Niepoprawna liczba: 1F (token 3: niedozwolony znak 'F'; czy chodziło o 0x1F?)",
+        "Niepoprawna liczba: 1..4 (token 4: zakres musi być domknięty; czy chodziło o 1..=4?)",
+        "Niepoprawna liczba: 5..=1 (token 5: zakres malejący; czy chodziło o 1..=5?)",
+    ]);
+    assert_eq!(messages("2147483648 0b2 1.5", IntWidth::I32), vec![
+        "Niepoprawna liczba: 2147483648 (token 1: wartość poza zakresem i32; użyj opcji --typ i64)",
+        "Niepoprawna liczba: 0b2 (token 2: cyfra '2' nie występuje w systemie dwójkowym)",
+        "Niepoprawna liczba: 1.5 (token 3: liczba z częścią ułamkową; użyj opcji --rzeczywiste, aby wczytać liczby rzeczywiste)",
+    ]);
+}
+
+#[test]
+fn a_single_range_is_limited() {
+    let longest = format!("1..={MAX_RANGE_LEN}");
+    assert_eq!(parse_integer_literal(&longest, IntWidth::I64), Ok(Literal::Range(1, MAX_RANGE_LEN as i128)));
+    let too_long = format!("0..={MAX_RANGE_LEN}");
+    assert_eq!(messages(&too_long, IntWidth::I64), vec![format!(
+        "Niepoprawna liczba: {too_long} (token 1: zakres ma więcej niż {MAX_RANGE_LEN} wartości)"
+    )]);
+}
+
+#[test]
+fn all_ranges_together_are_limited() {
+    let half = MAX_TOTAL_VALUES / 2;
+    let input = format!("1..={half} 1..={half} 7 1..={half} 8");
+    assert_eq!(messages(&input, IntWidth::I64), vec![format!(
+        "Niepoprawna liczba: 7 (token 3: łącznie więcej niż {MAX_TOTAL_VALUES} wartości; użyj opcji --strumien)"
+    )]);
+    let errors = parse_floats(&format!("0.5 1..={half} 1..={half}")).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), format!(
+        "Niepoprawna liczba: 1..={half} (token 3: łącznie więcej niż {MAX_TOTAL_VALUES} wartości)"
+    ));
+
+    // Tryb strumieniowy nie zapamiętuje wartości, więc nie ma łącznego limitu.
+    let options = parse_args(["--strumien"]).unwrap();
+    let report = run(&options, input.as_bytes()).unwrap();
+    assert_eq!(report[0], format!("Liczba wartości: {}", 3 * half + 2));
+}
+
+#[test]
+fn floats_accept_integers_and_ranges() {
+    assert_eq!(parse_floats("2.5 -1e3 0x10 1..=2").unwrap(), vec![2.5, -1000.0, 16.0, 1.0, 2.0]);
+    let errors = parse_floats("2 1.2.3 1O.5 inf").unwrap_err();
+    let errors: Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+    assert_eq!(errors, vec![
+        "Niepoprawna liczba: 1.2.3 (token 2: niepoprawna liczba rzeczywista)",
+        "Niepoprawna liczba: 1O.5 (token 3: niepoprawna liczba rzeczywista; czy chodziło o 10.5?)",
+        "Niepoprawna liczba: inf (token 4: wartość nieskończona lub nieokreślona)",
+    ]);
+}
@@ -0,0 +1,66 @@
+use a_ex_5::outliers::{detect, iqr_fences, parse_methods, without_outliers, z_score, Method};
+use a_ex_5::{parse_args, run};
+
+const VALUES: [f64; 12] = [12.0, 15.0, 11.0, 14.0, 13.0, 950.0, 12.0, 16.0, 13.0, 14.0, 12.0, 15.0];
+
+#[test]
+fn methods_and_thresholds_are_parsed() {
+    assert_eq!(parse_methods("iqr=3, Z=2.5").unwrap(), vec![Method::Iqr(3.0), Method::ZScore(2.5)]);
+    assert_eq!(parse_methods("z").unwrap(), vec![Method::ZScore(3.0)]);
+    assert_eq!(parse_methods(",").unwrap_err(), "Nie wybrano żadnej metody wykrywania.");
+    assert_eq!(parse_methods("z=-1").unwrap_err(), "Niepoprawny próg: -1");
+    assert_eq!(parse_methods("mad").unwrap_err(), "Nieznana metoda wykrywania: mad");
+    assert_eq!(parse_args(["--odstajace"]).unwrap().outliers, Some(Method::DEFAULT.to_vec()));
+    assert_eq!(
+        parse_args(["--strumien", "--odstajace"]).unwrap_err(),
+        "Opcji --odstajace nie można łączyć z --strumien."
+    );
+}
+
+#[test]
+fn fences_and_scores() {
+    assert_eq!(iqr_fences(&[1.0, 2.0, 3.0, 4.0, 5.0], 1.5), Some((-1.0, 7.0)));
+    assert_eq!(z_score(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0], 9.0), Some(2.0));
+    assert_eq!(z_score(&[3.0, 3.0], 3.0), None);
+    assert!(detect(&[3.0, 3.0, 3.0], &Method::DEFAULT).is_empty());
+}
+
+#[test]
+fn outliers_keep_their_input_positions() {
+    let outliers = detect(&VALUES, &Method::DEFAULT);
+    assert_eq!(outliers.len(), 1);
+    assert_eq!((outliers[0].position, outliers[0].value), (6, 950.0));
+    assert_eq!(outliers[0].methods, vec![Method::Iqr(1.5), Method::ZScore(3.0)]);
+    assert_eq!(without_outliers(&VALUES, &outliers).len(), 11);
+
+    let repeated = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 50.0, 50.0];
+    let positions: Vec<usize> = detect(&repeated, &[Method::Iqr(1.5)]).iter().map(|o| o.position).collect();
+    assert_eq!(positions, vec![7, 8]);
+}
+
+#[test]
+fn report_lists_outliers_and_statistics_without_them() {
+    let options = parse_args(["--odstajace=z=2", "--pokaz", "srednia,mediana"]).unwrap();
+    let input = "12 15 11 14 13 950 12 16 13 14 12 15\n";
+    assert_eq!(run(&options, input.as_bytes()).unwrap(), vec![
+        "Średnia: 91.42",
+        "Mediana: 13.50",
+        "Wartości odstające (1):",
+        "  pozycja 6: 950 (z = 3.32)",
+        "Bez wartości odstających:",
+        "  Średnia: 13.36",
+        "  Mediana: 13",
+    ]);
+
+    let options = parse_args(["--odstajace=iqr"]).unwrap();
+    assert_eq!(run(&options, "1 2 3 4 5".as_bytes()).unwrap().last().unwrap(), "Wartości odstające: brak");
+    assert_eq!(run(&options, "1..=4 40".as_bytes()).unwrap()[4..], [
+        "Wartości odstające (1):",
+        "  pozycja 5: 40 (IQR: poza [-1, 7])",
+        "Bez wartości odstających:",
+        "  Liczby (4): 1 2 3 4",
+        "  Minimum: 1",
+        "  Maksimum: 4",
+        "  Suma: 10",
+    ]);
+}
@@ -0,0 +1,291 @@
+//! Rejestr ćwiczeń dostępnych z jednego programu.
+//!
+//! Każde ćwiczenie to nazwa, krótki opis i funkcja uruchamiająca, która przekazuje
+//! argumenty oraz wejście i wyjście do istniejącego punktu wejścia biblioteki ćwiczenia.
+use std::any::Any;
+use std::io::{BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Funkcja uruchamiająca ćwiczenie: argumenty (bez nazwy ćwiczenia), wejście, wyjście
+/// i wyjście błędów (komunikaty dodatkowe, np. raport połączeń z `a_ex_4`).
+/// Zwraca kod wyjścia ćwiczenia (`0` oznacza sukces).
+pub type Runner =
+    fn(&[String], &mut dyn BufRead, &mut dyn Write, &mut dyn Write) -> Result<i32, String>;
+
+/// Ćwiczenie dostępne w programie.
+#[derive(Clone, Copy)]
+pub struct Exercise {
+    /// Nazwa podawana w wierszu poleceń, np. `a_ex_2`.
+    pub name: &'static str,
+    pub description: &'static str,
+    pub run: Runner,
+}
+
+/// Wszystkie ćwiczenia w kolejności listy.
+pub const EXERCISES: &[Exercise] = &[
+    Exercise {
+        name: "hello",
+        description: "Zadanie wprowadzające: powitanie",
+        run: run_hello,
+    },
+    Exercise {
+        name: "a_ex_2",
+        description: "Histogram liter",
+        run: run_a_ex_2,
+    },
+    Exercise {
+        name: "a_ex_3",
+        description: "Sumator CLI (sesja interaktywna)",
+        run: run_a_ex_3,
+    },
+    Exercise {
+        name: "a_ex_4",
+        description: "Checklist tematów",
+        run: run_a_ex_4,
+    },
+    Exercise {
+        name: "a_ex_5",
+        description: "Raport z liczb",
+        run: run_a_ex_5,
+    },
+    Exercise {
+        name: "b_ex_2",
+        description: "Podsumowanie zadań",
+        run: run_b_ex_2,
+    },
+    Exercise {
+        name: "b_ex_4",
+        description: "Raport z testów regresyjnych",
+        run: run_b_ex_4,
+    },
+    Exercise {
+        name: "x_ex_2",
+        description: "Segmentowe minimum z leniwym dodawaniem",
+        run: run_x_ex_2,
+    },
+    Exercise {
+        name: "x_ex_3",
+        description: "Logarytmiczne zapytania na drzewie",
+        run: run_x_ex_3,
+    },
+    Exercise {
+        name: "x_ex_4",
+        description: "Maksymalny przepływ i minimalny przekrój",
+        run: run_x_ex_4,
+    },
+    Exercise {
+        name: "x_ex_5",
+        description: "FFT-owa korelacja sekwencji",
+        run: run_x_ex_5,
+    },
+];
+
+/// Szuka ćwiczenia po nazwie (bez względu na wielkość liter, `A_EX_2` też pasuje).
+pub fn find(name: &str) -> Option<&'static Exercise> {
+    EXERCISES
+        .iter()
+        .find(|exercise| exercise.name.eq_ignore_ascii_case(name))
+}
+
+/// Uruchamia ćwiczenie, zamieniając panikę (np. z nierozwiązanego `todo!()`) na
+/// jednolinijkowy błąd. Komunikat domyślnego hooka paniki wycisza wywołujący.
+pub fn run_exercise(
+    exercise: &Exercise,
+    args: &[String],
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+    errors: &mut dyn Write,
+) -> Result<i32, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        (exercise.run)(args, input, output, errors)
+    }))
+    .unwrap_or_else(|payload| {
+        Err(format!(
+            "Ćwiczenie {} przerwane paniką: {}",
+            exercise.name,
+            panic_message(payload)
+        ))
+    })
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "nieznana przyczyna".to_string())
+}
+
+/// Lista ćwiczeń z opisami, wyrównana do najdłuższej nazwy.
+pub fn listing() -> Vec<String> {
+    let width = EXERCISES.iter().map(|e| e.name.len()).max().unwrap_or(0);
+    EXERCISES
+        .iter()
+        .map(|exercise| format!("  {:<width$}  {}", exercise.name, exercise.description))
+        .collect()
+}
+
+fn no_args(args: &[String]) -> Result<(), String> {
+    match args.first() {
+        Some(arg) => Err(format!("Nieznany argument: {arg}")),
+        None => Ok(()),
+    }
+}
+
+fn write_lines<S: AsRef<str>>(output: &mut dyn Write, lines: &[S]) -> Result<(), String> {
+    for line in lines {
+        writeln!(output, "{}", line.as_ref()).map_err(io_error)?;
+    }
+    Ok(())
+}
+
+fn io_error(err: std::io::Error) -> String {
+    format!("Błąd wejścia/wyjścia: {err}")
+}
+
+fn run_hello(
+    args: &[String],
+    _: &mut dyn BufRead,
+    output: &mut dyn Write,
+    _: &mut dyn Write,
+) -> Result<i32, String> {
+    no_args(args)?;
+    write_lines(output, &["Hello Rust Lab"])?;
+    Ok(0)
+}
+
+fn run_a_ex_2(
+    args: &[String],
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+    _: &mut dyn Write,
+) -> Result<i32, String> {
+    let options = a_ex_2::parse_args(args)?;
+    let mut text = String::new();
+    if options.files.is_empty() {
+        input.read_to_string(&mut text).map_err(io_error)?;
+    }
+    // Tak jak `a_ex_2::read_input`, ale `-` oznacza przekazane wejście, a nie stdin procesu.
+    for path in &options.files {
+        if path == "-" {
+            input.read_to_string(&mut text).map_err(io_error)?;
+            if !text.ends_with('\n') {
+                text.push('\n');
+            }
+        } else {
+            text.push_str(&a_ex_2::read_input(std::slice::from_ref(path))?);
+        }
+    }
+    write_lines(output, &a_ex_2::run_with_options(&text, &options)?)?;
+    Ok(0)
+}
+
+fn run_a_ex_3(
+    args: &[String],
+    input: &mut dyn BufRead,
+    mut output: &mut dyn Write,
+    _: &mut dyn Write,
+) -> Result<i32, String> {
+    if args.len() > 1 {
+        return Err(format!("Nieznany argument: {}", args[1]));
+    }
+    let mode = a_ex_3::parse_mode(args.first().map(String::as_str))?;
+    a_ex_3::run_interactive(input, &mut output, mode).map_err(io_error)?;
+    Ok(0)
+}
+
+fn run_a_ex_4(
+    args: &[String],
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+    errors: &mut dyn Write,
+) -> Result<i32, String> {
+    let options = a_ex_4::parse_args(args)?;
+    let result = a_ex_4::run(&options, input)?;
+    write_lines(output, &result.stdout)?;
+    write_lines(errors, &result.stderr)?;
+    Ok(0)
+}
+
+fn run_a_ex_5(
+    args: &[String],
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+    _: &mut dyn Write,
+) -> Result<i32, String> {
+    let options = a_ex_5::parse_args(args)?;
+    write_lines(output, &a_ex_5::run(&options, input)?)?;
+    Ok(0)
+}
+
+fn run_b_ex_2(
+    args: &[String],
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+    errors: &mut dyn Write,
+) -> Result<i32, String> {
+    let outcome = b_ex_2::run_program(args, input);
+    write_lines(output, &outcome.stdout)?;
+    write_lines(errors, &outcome.stderr)?;
+    Ok(outcome.exit_code)
+}
+
+fn run_b_ex_4(
+    args: &[String],
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+    _: &mut dyn Write,
+) -> Result<i32, String> {
+    no_args(args)?;
+    let lines = b_ex_4::collect_report(input).map_err(|err| err.to_string())?;
+    write_lines(output, &lines)?;
+    Ok(0)
+}
+
+fn run_x_ex_2(
+    args: &[String],
+    input: &mut dyn BufRead,
+    mut output: &mut dyn Write,
+    _: &mut dyn Write,
+) -> Result<i32, String> {
+    no_args(args)?;
+    let answers = x_ex_2::solve_from_reader(input)?;
+    x_ex_2::write_answers(&answers, &mut output).map_err(io_error)?;
+    Ok(0)
+}
+
+fn run_x_ex_3(
+    args: &[String],
+    input: &mut dyn BufRead,
+    mut output: &mut dyn Write,
+    _: &mut dyn Write,
+) -> Result<i32, String> {
+    no_args(args)?;
+    let answers = x_ex_3::solve_from_reader(input)?;
+    x_ex_3::write_answers(&answers, &mut output).map_err(io_error)?;
+    Ok(0)
+}
+
+fn run_x_ex_4(
+    args: &[String],
+    input: &mut dyn BufRead,
+    mut output: &mut dyn Write,
+    _: &mut dyn Write,
+) -> Result<i32, String> {
+    no_args(args)?;
+    let summary = x_ex_4::solve_from_reader(input)?;
+    x_ex_4::write_answer(&summary, &mut output).map_err(io_error)?;
+    Ok(0)
+}
+
+fn run_x_ex_5(
+    args: &[String],
+    input: &mut dyn BufRead,
+    mut output: &mut dyn Write,
+    _: &mut dyn Write,
+) -> Result<i32, String> {
+    no_args(args)?;
+    let answers = x_ex_5::solve_from_reader(input)?;
+    x_ex_5::write_answers(&answers, &mut output).map_err(io_error)?;
+    Ok(0)
+}
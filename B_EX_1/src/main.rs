@@ -1,3 +1,66 @@
+use std::env;
+use std::io::{self, Write};
+use std::panic;
+use std::process;
+
+pub mod exercises;
+
+/// Nazwy, po których program wypisuje listę ćwiczeń zamiast uruchamiać jedno z nich.
+const LIST_COMMANDS: [&str; 3] = ["lista", "--lista", "--help"];
+
+/// Linie pomocy: sposób użycia i lista dostępnych ćwiczeń.
+pub fn usage() -> Vec<String> {
+    let mut lines = vec![
+        "Użycie: b_ex_1 <ćwiczenie> [argumenty...]".to_string(),
+        String::new(),
+        "Dostępne ćwiczenia:".to_string(),
+    ];
+    lines.extend(exercises::listing());
+    lines
+}
+
+/// Uruchamia ćwiczenie o podanej nazwie; dane czyta ze standardowego wejścia,
+/// a wynik wypisuje na standardowe wyjście (komunikaty dodatkowe — na stderr).
+/// Zwraca kod wyjścia ćwiczenia.
+pub fn dispatch(name: &str, args: &[String]) -> Result<i32, String> {
+    let exercise = exercises::find(name)
+        .ok_or_else(|| format!("Nieznane ćwiczenie: {name} (listę wypisuje `b_ex_1 lista`)"))?;
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut output = stdout.lock();
+    let mut errors = io::stderr().lock();
+    let code =
+        exercises::run_exercise(exercise, args, &mut stdin.lock(), &mut output, &mut errors)?;
+    output
+        .flush()
+        .map_err(|err| format!("Błąd wejścia/wyjścia: {err}"))?;
+    Ok(code)
+}
+
 pub fn main() {
-    // Write some hello ;D
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some((name, rest)) = args.split_first() else {
+        for line in usage() {
+            println!("{line}");
+        }
+        return;
+    };
+
+    if LIST_COMMANDS.contains(&name.as_str()) {
+        for line in usage() {
+            println!("{line}");
+        }
+        return;
+    }
+
+    // Paniki ćwiczeń (np. z `todo!()`) zamienia na jednolinijkowy błąd `run_exercise`.
+    panic::set_hook(Box::new(|_| {}));
+    match dispatch(name, rest) {
+        Ok(0) => {}
+        Ok(code) => process::exit(code),
+        Err(message) => {
+            eprintln!("{message}");
+            process::exit(1);
+        }
+    }
 }
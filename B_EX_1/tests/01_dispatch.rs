@@ -0,0 +1,67 @@
+use std::fs;
+
+use b_ex_1::exercises::{find, listing, run_exercise, EXERCISES};
+
+fn run(name: &str, args: &[&str], input: &str) -> Result<String, String> {
+    let (code, output, _) = run_with_code(name, args, input)?;
+    assert_eq!(code, 0);
+    Ok(output)
+}
+
+/// Uruchamia ćwiczenie jak `b_ex_1`; zwraca kod wyjścia, stdout i stderr.
+fn run_with_code(name: &str, args: &[&str], input: &str) -> Result<(i32, String, String), String> {
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    let (mut output, mut errors) = (Vec::new(), Vec::new());
+    let exercise = find(name).expect("ćwiczenie istnieje");
+    let code = run_exercise(exercise, &args, &mut input.as_bytes(), &mut output, &mut errors)?;
+    Ok((code, String::from_utf8(output).unwrap(), String::from_utf8(errors).unwrap()))
+}
+
+#[test]
+fn exercises_are_found_case_insensitively() {
+    assert_eq!(find("A_EX_2").unwrap().name, "a_ex_2");
+    assert!(find("a_ex_9").is_none());
+    assert_eq!(listing().len(), EXERCISES.len());
+    assert_eq!(run("hello", &[], "").unwrap(), "Hello Rust Lab\n");
+    assert_eq!(run("hello", &["-x"], ""), Err("Nieznany argument: -x".to_string()));
+}
+
+#[test]
+fn dash_reads_the_injected_input() {
+    let path = std::env::temp_dir().join(format!("b_ex_1_dispatch_{}.txt", std::process::id()));
+    fs::write(&path, "aa").unwrap();
+    let path = path.display().to_string();
+
+    assert_eq!(run("a_ex_2", &[], "ab").unwrap(), "a: 1\nb: 1\n");
+    assert_eq!(run("a_ex_2", &["-"], "ab").unwrap(), "a: 1\nb: 1\n");
+    assert_eq!(run("a_ex_2", &[path.as_str(), "-"], "b").unwrap(), "a: 2\nb: 1\n");
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn a_ex_4_merges_go_to_the_error_output() {
+    let (code, output, errors) = run_with_code("a_ex_4", &["--scal", "--format", "json"], "Go, go").unwrap();
+    assert_eq!(code, 0);
+    assert!(output.starts_with("{\n"));
+    assert_eq!(errors, "Połączono: go → Go\n");
+}
+
+#[test]
+fn b_ex_2_runs_the_whole_program() {
+    assert_eq!(run("b_ex_2", &["--stan"], "A | high | todo\n").unwrap(), "A | high | TODO\n");
+    assert_eq!(
+        run_with_code("b_ex_2", &[], "A | urgent | todo\n"),
+        Ok((1, String::new(), "line 1: unknown priority \"urgent\"\n".to_string()))
+    );
+    assert_eq!(
+        run_with_code("b_ex_2", &["--inne"], ""),
+        Ok((2, String::new(), "Nieznany argument: --inne\n".to_string()))
+    );
+}
+
+#[test]
+fn unfinished_exercises_fail_with_one_line() {
+    let message = run_with_code("b_ex_4", &[], "suite::test | pass | 1\n").unwrap_err();
+    assert!(message.starts_with("Ćwiczenie b_ex_4 przerwane paniką: not yet implemented"), "{message}");
+    assert!(!message.contains('\n'));
+}
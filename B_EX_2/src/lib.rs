@@ -1,5 +1,12 @@
 use std::collections::BTreeMap;
-use std::io::BufRead;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+
+pub mod output;
+pub mod query;
+pub mod workflow;
 
 pub mod domain {
     //! Domain types and parsing logic for individual task records.
@@ -22,13 +29,30 @@ pub mod domain {
 
         /// Label shown in the report (`high`, `medium`, `low`).
         pub fn label(self) -> &'static str {
-            todo!("return the lowercase label for each priority")
+            match self {
+                Priority::High => "high",
+                Priority::Medium => "medium",
+                Priority::Low => "low",
+            }
         }
     }
 
     impl fmt::Display for Priority {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            todo!("print the label defined in Priority::label")
+            f.write_str(self.label())
+        }
+    }
+
+    impl FromStr for Priority {
+        type Err = TaskParseError;
+
+        /// Case-insensitive; the error keeps the trimmed token as written.
+        fn from_str(raw: &str) -> Result<Self, Self::Err> {
+            let raw = raw.trim();
+            Priority::all()
+                .into_iter()
+                .find(|priority| priority.label().eq_ignore_ascii_case(raw))
+                .ok_or_else(|| TaskParseError::InvalidPriority(raw.to_string()))
         }
     }
 
@@ -48,13 +72,90 @@ pub mod domain {
 
         /// Label used when printing to stdout (e.g. `TODO`, `IN_PROGRESS`, `DONE`).
         pub fn label(self) -> &'static str {
-            todo!("return the uppercase label for each status")
+            match self {
+                Status::Todo => "TODO",
+                Status::InProgress => "IN_PROGRESS",
+                Status::Done => "DONE",
+            }
         }
     }
 
     impl fmt::Display for Status {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            todo!("print the label defined in Status::label")
+            f.write_str(self.label())
+        }
+    }
+
+    impl FromStr for Status {
+        type Err = TaskParseError;
+
+        /// Case-insensitive; the error keeps the trimmed token as written.
+        fn from_str(raw: &str) -> Result<Self, Self::Err> {
+            let raw = raw.trim();
+            Status::all()
+                .into_iter()
+                .find(|status| status.label().eq_ignore_ascii_case(raw))
+                .ok_or_else(|| TaskParseError::InvalidStatus(raw.to_string()))
+        }
+    }
+
+    /// Calendar date written as ISO `YYYY-MM-DD`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct DueDate {
+        pub year: u16,
+        pub month: u8,
+        pub day: u8,
+    }
+
+    impl DueDate {
+        /// Number of days in `month` (1-based) of `year`.
+        pub fn days_in_month(year: u16, month: u8) -> u8 {
+            match month {
+                1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+                4 | 6 | 9 | 11 => 30,
+                2 if year.is_multiple_of(4)
+                    && (!year.is_multiple_of(100) || year.is_multiple_of(400)) =>
+                {
+                    29
+                }
+                2 => 28,
+                _ => 0,
+            }
+        }
+    }
+
+    impl fmt::Display for DueDate {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+        }
+    }
+
+    impl FromStr for DueDate {
+        type Err = TaskParseError;
+
+        /// Accepts exactly `YYYY-MM-DD` naming an existing day.
+        fn from_str(raw: &str) -> Result<Self, Self::Err> {
+            let raw = raw.trim();
+            let invalid = || TaskParseError::InvalidDueDate(raw.to_string());
+            let parts: Vec<&str> = raw.split('-').collect();
+            let [year, month, day] = parts[..] else {
+                return Err(invalid());
+            };
+            let digits = |part: &str, len: usize| {
+                part.len() == len && part.bytes().all(|b| b.is_ascii_digit())
+            };
+            if !(digits(year, 4) && digits(month, 2) && digits(day, 2)) {
+                return Err(invalid());
+            }
+            let date = DueDate {
+                year: year.parse().map_err(|_| invalid())?,
+                month: month.parse().map_err(|_| invalid())?,
+                day: day.parse().map_err(|_| invalid())?,
+            };
+            if date.day == 0 || date.day > DueDate::days_in_month(date.year, date.month) {
+                return Err(invalid());
+            }
+            Ok(date)
         }
     }
 
@@ -63,41 +164,210 @@ pub mod domain {
     pub enum TaskParseError {
         /// No valid tasks were provided.
         NoTasks,
-        /// Line does not have exactly three fields split by `|`.
+        /// Line does not have three fields split by `|`, or an optional field is not recognised
+        /// or is repeated.
         InvalidFormat(String),
-        /// Unknown priority (middle field).
+        /// Unknown priority (second field).
         InvalidPriority(String),
-        /// Unknown status (last field).
+        /// Unknown status (third field).
         InvalidStatus(String),
+        /// Assignee field (`@name`) with an empty name or whitespace inside it.
+        InvalidAssignee(String),
+        /// Due date field that is not an existing `YYYY-MM-DD` day.
+        InvalidDueDate(String),
+        /// Tag token in the tags field that is not a non-empty `#tag`.
+        InvalidTag(String),
+        /// Input could not be read (holds the line number and the I/O error).
+        Unreadable(String),
     }
 
     impl fmt::Display for TaskParseError {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            todo!("map error variants to human readable messages defined in README")
+            match self {
+                TaskParseError::NoTasks => write!(f, "Brak zadan"),
+                TaskParseError::InvalidFormat(line) => {
+                    write!(f, "Niepoprawny format linii: {line}")
+                }
+                TaskParseError::InvalidPriority(value) => write!(f, "Nieznany priorytet: {value}"),
+                TaskParseError::InvalidStatus(value) => write!(f, "Nieznany status: {value}"),
+                TaskParseError::InvalidAssignee(value) => {
+                    write!(f, "Niepoprawna osoba odpowiedzialna: {value}")
+                }
+                TaskParseError::InvalidDueDate(value) => write!(f, "Niepoprawny termin: {value}"),
+                TaskParseError::InvalidTag(value) => write!(f, "Niepoprawny tag: {value}"),
+                TaskParseError::Unreadable(value) => {
+                    write!(f, "Nie mozna odczytac wejscia: {value}")
+                }
+            }
         }
     }
 
     impl std::error::Error for TaskParseError {}
 
+    impl TaskParseError {
+        /// Name of the field the error points at, or `None` when the whole line is at fault.
+        pub fn field(&self) -> Option<&'static str> {
+            match self {
+                TaskParseError::NoTasks
+                | TaskParseError::InvalidFormat(_)
+                | TaskParseError::Unreadable(_) => None,
+                TaskParseError::InvalidPriority(_) => Some("priority"),
+                TaskParseError::InvalidStatus(_) => Some("status"),
+                TaskParseError::InvalidAssignee(_) => Some("assignee"),
+                TaskParseError::InvalidDueDate(_) => Some("due date"),
+                TaskParseError::InvalidTag(_) => Some("tag"),
+            }
+        }
+    }
+
     /// Single task record described in the input.
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub struct Task {
         pub title: String,
         pub priority: Priority,
         pub status: Status,
+        /// Person responsible for the task (`@name`), without the `@`.
+        pub assignee: Option<String>,
+        pub due: Option<DueDate>,
+        /// Tags in input order, without the leading `#`.
+        pub tags: Vec<String>,
+    }
+
+    impl Task {
+        /// Task without any of the optional fields.
+        pub fn new(title: impl Into<String>, priority: Priority, status: Status) -> Self {
+            Task {
+                title: title.into(),
+                priority,
+                status,
+                assignee: None,
+                due: None,
+                tags: Vec::new(),
+            }
+        }
+
+        /// Stores one optional field whose kind was already recognised by [`OptionalField::of`].
+        fn apply_optional_field(&mut self, field: &str) -> Result<(), TaskParseError> {
+            match OptionalField::of(field) {
+                Some(OptionalField::Assignee) => {
+                    let name = &field[1..];
+                    if name.is_empty() || name.contains(char::is_whitespace) {
+                        return Err(TaskParseError::InvalidAssignee(field.to_string()));
+                    }
+                    self.assignee = Some(name.to_string());
+                }
+                Some(OptionalField::Tags) => {
+                    for token in field.split_whitespace() {
+                        match token.strip_prefix('#') {
+                            Some(tag) if !tag.is_empty() && !tag.contains('#') => {
+                                self.tags.push(tag.to_string())
+                            }
+                            _ => return Err(TaskParseError::InvalidTag(token.to_string())),
+                        }
+                    }
+                }
+                Some(OptionalField::Due) => self.due = Some(field.parse()?),
+                None => {}
+            }
+            Ok(())
+        }
+    }
+
+    /// Kind of an optional field, recognised by its first character.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum OptionalField {
+        Assignee,
+        Tags,
+        Due,
+    }
+
+    impl OptionalField {
+        fn of(field: &str) -> Option<OptionalField> {
+            match field.chars().next()? {
+                '@' => Some(OptionalField::Assignee),
+                '#' => Some(OptionalField::Tags),
+                c if c.is_ascii_digit() => Some(OptionalField::Due),
+                _ => None,
+            }
+        }
+    }
+
+    impl Task {
+        /// Like [`Task::from_str`], but reports every invalid field of the line instead of
+        /// only the first one. A line with a broken shape yields a single
+        /// [`TaskParseError::InvalidFormat`], since its fields cannot be told apart.
+        pub fn parse_line(line: &str) -> Result<Task, Vec<TaskParseError>> {
+            let invalid_format = || vec![TaskParseError::InvalidFormat(line.to_string())];
+            let fields: Vec<&str> = line.split('|').map(str::trim).collect();
+            let [title, priority, status, ref optional @ ..] = fields[..] else {
+                return Err(invalid_format());
+            };
+            let mut seen = Vec::new();
+            for field in optional {
+                match OptionalField::of(field) {
+                    Some(kind) if !seen.contains(&kind) => seen.push(kind),
+                    _ => return Err(invalid_format()),
+                }
+            }
+
+            let mut errors = Vec::new();
+            let priority = priority.parse().map_err(|err| errors.push(err)).ok();
+            let status = status.parse().map_err(|err| errors.push(err)).ok();
+            let mut task = Task::new(
+                title,
+                priority.unwrap_or(Priority::Low),
+                status.unwrap_or(Status::Todo),
+            );
+            for field in optional {
+                if let Err(err) = task.apply_optional_field(field) {
+                    errors.push(err);
+                }
+            }
+            if errors.is_empty() {
+                Ok(task)
+            } else {
+                Err(errors)
+            }
+        }
+    }
+
+    impl fmt::Display for Task {
+        /// Writes the task back as an input line, e.g. `Deploy | high | TODO | @ania | #ops`.
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} | {} | {}", self.title, self.priority, self.status)?;
+            if let Some(assignee) = &self.assignee {
+                write!(f, " | @{assignee}")?;
+            }
+            if let Some(due) = self.due {
+                write!(f, " | {due}")?;
+            }
+            if !self.tags.is_empty() {
+                let tags: Vec<String> = self.tags.iter().map(|tag| format!("#{tag}")).collect();
+                write!(f, " | {}", tags.join(" "))?;
+            }
+            Ok(())
+        }
     }
 
     impl FromStr for Task {
         type Err = TaskParseError;
 
-        /// Parses a `title | priority | status` line.
+        /// Parses a `title | priority | status` line, optionally followed by further `|`-separated
+        /// fields in any order: an assignee `@name`, a due date `YYYY-MM-DD` and tags `#a #b`.
+        ///
+        /// The shape of the line is checked first, so an unrecognised or repeated optional field
+        /// is reported as [`TaskParseError::InvalidFormat`] before any field value is validated.
+        /// Otherwise the first invalid field wins.
         fn from_str(line: &str) -> Result<Self, Self::Err> {
-            todo!("split the fields, trim them and return errors that highlight the issue")
+            Task::parse_line(line).map_err(|mut errors| errors.remove(0))
         }
     }
 }
 
 use domain::{Priority, Status, Task, TaskParseError};
+use output::OutputFormat;
+use query::Query;
+use workflow::{Replay, TransitionRules};
 
 /// Aggregated data for a specific status.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -109,15 +379,92 @@ pub struct StatusSummary {
 impl StatusSummary {
     /// Returns the number of tasks in this status.
     pub fn total(&self) -> usize {
-        todo!("sum all values stored in the counts map")
+        self.counts.values().sum()
     }
 }
 
+/// Parse error together with the 1-based number of the line it comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    pub line: usize,
+    pub error: TaskParseError,
+}
+
+impl LineError {
+    /// Name of the offending field, see [`TaskParseError::field`].
+    pub fn field(&self) -> Option<&'static str> {
+        self.error.field()
+    }
+}
+
+impl fmt::Display for LineError {
+    /// Compiler-style diagnostic, e.g. `line 14: unknown priority "urgent"`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.error {
+            TaskParseError::NoTasks => write!(f, "no tasks"),
+            TaskParseError::InvalidFormat(line) => {
+                write!(f, "invalid line format \"{}\"", line.trim())
+            }
+            TaskParseError::InvalidPriority(value) => write!(f, "unknown priority \"{value}\""),
+            TaskParseError::InvalidStatus(value) => write!(f, "unknown status \"{value}\""),
+            TaskParseError::InvalidAssignee(value) => write!(f, "invalid assignee \"{value}\""),
+            TaskParseError::InvalidDueDate(value) => write!(f, "invalid due date \"{value}\""),
+            TaskParseError::InvalidTag(value) => write!(f, "invalid tag \"{value}\""),
+            TaskParseError::Unreadable(value) => write!(f, "unreadable input: {value}"),
+        }
+    }
+}
+
+impl std::error::Error for LineError {}
+
+/// Result of parsing the whole input without stopping at the first bad line.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Diagnosis {
+    /// Tasks from all valid lines, in input order.
+    pub tasks: Vec<Task>,
+    /// Errors from all invalid lines, in input order.
+    pub errors: Vec<LineError>,
+}
+
+/// Non-empty lines of `input` with their 1-based line numbers.
+fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| (index + 1, line))
+}
+
 /// Parses multiple tasks from raw text, skipping empty lines.
 ///
 /// At least one valid task must be present or [`TaskParseError::NoTasks`] is returned.
 pub fn parse_tasks(input: &str) -> Result<Vec<Task>, TaskParseError> {
-    todo!("split the input into lines, skip blanks and delegate to Task::from_str")
+    let tasks = numbered_lines(input)
+        .map(|(_, line)| line.parse())
+        .collect::<Result<Vec<Task>, _>>()?;
+    if tasks.is_empty() {
+        return Err(TaskParseError::NoTasks);
+    }
+    Ok(tasks)
+}
+
+/// Diagnostic counterpart of [`parse_tasks`]: parses every line and collects all errors
+/// with their line numbers instead of returning on the first one.
+pub fn diagnose_tasks(input: &str) -> Diagnosis {
+    let mut diagnosis = Diagnosis::default();
+    for (number, line) in numbered_lines(input) {
+        match Task::parse_line(line) {
+            Ok(task) => diagnosis.tasks.push(task),
+            Err(errors) => diagnosis
+                .errors
+                .extend(errors.into_iter().map(|error| LineError {
+                    line: number,
+                    error,
+                })),
+        }
+    }
+    diagnosis
 }
 
 /// Builds summaries for every status in the required order.
@@ -125,7 +472,17 @@ pub fn parse_tasks(input: &str) -> Result<Vec<Task>, TaskParseError> {
 /// The result must contain entries for all statuses (`TODO`, `IN_PROGRESS`, `DONE`) even when the
 /// total count is zero. Each entry keeps per-priority counters within a `BTreeMap`.
 pub fn summarize_by_status(tasks: &[Task]) -> Vec<StatusSummary> {
-    todo!("initialise summaries for all statuses and increment counts per priority")
+    Status::all()
+        .into_iter()
+        .map(|status| {
+            let mut counts: BTreeMap<Priority, usize> =
+                Priority::all().into_iter().map(|p| (p, 0)).collect();
+            for task in tasks.iter().filter(|task| task.status == status) {
+                *counts.entry(task.priority).or_insert(0) += 1;
+            }
+            StatusSummary { status, counts }
+        })
+        .collect()
 }
 
 /// Formats aggregated data ready to be displayed.
@@ -134,12 +491,205 @@ pub fn summarize_by_status(tasks: &[Task]) -> Vec<StatusSummary> {
 /// `TODO: 2 (high: 1, medium: 1, low: 0)`
 /// while keeping priority order `high`, `medium`, `low`.
 pub fn format_summary(summary: &[StatusSummary]) -> Vec<String> {
-    todo!("build the expected lines based on summarize_by_status output")
+    summary
+        .iter()
+        .map(|entry| {
+            let counts: Vec<String> = Priority::all()
+                .into_iter()
+                .map(|p| format!("{p}: {}", entry.counts.get(&p).copied().unwrap_or(0)))
+                .collect();
+            format!(
+                "{}: {} ({})",
+                entry.status,
+                entry.total(),
+                counts.join(", ")
+            )
+        })
+        .collect()
+}
+
+/// Reads the whole input as text.
+///
+/// Invalid UTF-8 is replaced with `U+FFFD`, so the line still reaches the parser and is
+/// reported there with its number. An I/O error is returned as
+/// [`TaskParseError::Unreadable`] naming the line that could not be read.
+pub fn read_input<R: BufRead>(mut reader: R) -> Result<String, TaskParseError> {
+    let mut lines = Vec::new();
+    let mut buffer = Vec::new();
+    loop {
+        buffer.clear();
+        match reader.read_until(b'\n', &mut buffer) {
+            Ok(0) => break,
+            Ok(_) => {
+                let line = buffer.strip_suffix(b"\n").unwrap_or(&buffer);
+                let line = line.strip_suffix(b"\r").unwrap_or(line);
+                lines.push(String::from_utf8_lossy(line).into_owned());
+            }
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => {
+                let line = lines.len() + 1;
+                return Err(TaskParseError::Unreadable(format!("linia {line}: {err}")));
+            }
+        }
+    }
+    Ok(lines.join("\n"))
 }
 
 /// Full pipeline: read, parse, aggregate and format.
 pub fn run_from_reader<R: BufRead>(reader: R) -> Result<Vec<String>, TaskParseError> {
-    todo!("read the text, call parse_tasks -> summarize_by_status -> format_summary")
+    let tasks = parse_tasks(&read_input(reader)?)?;
+    Ok(format_summary(&summarize_by_status(&tasks)))
+}
+
+/// Settings read from the command line of the binary.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Only tasks matching this query are summarized (`--zapytanie`).
+    pub query: Query,
+    /// Format of the summary (`--format`).
+    pub format: OutputFormat,
+    /// Event log replayed onto the tasks before summarizing (`--zdarzenia`).
+    pub events: Option<PathBuf>,
+    /// Status changes allowed in the event log without `force` (`--reguly`).
+    pub rules: TransitionRules,
+    /// Print the tasks as input lines instead of the summary (`--stan`).
+    pub state: bool,
+}
+
+/// Returns the value of a flag: given after `=` or as the next argument.
+fn take_value<I>(flag: &str, inline: Option<&str>, rest: &mut I) -> Result<String, String>
+where
+    I: Iterator<Item = String>,
+{
+    match inline {
+        Some(value) => Ok(value.to_string()),
+        None => rest
+            .next()
+            .ok_or_else(|| format!("Brak wartosci dla {flag}")),
+    }
+}
+
+/// Reads [`Options`] from the program arguments: `--zapytanie <query>`, `--format <name>`,
+/// `--zdarzenia <file>`, `--reguly <rules>` and `--stan`.
+pub fn parse_args<I>(args: I) -> Result<Options, String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut options = Options::default();
+    let mut args = args.into_iter().map(|arg| arg.as_ref().to_string());
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value)),
+            _ => (arg.as_str(), None),
+        };
+
+        match flag {
+            "--zapytanie" => {
+                options.query = take_value(flag, inline, &mut args)?
+                    .parse()
+                    .map_err(|err: query::QueryError| err.to_string())?
+            }
+            "--format" => options.format = take_value(flag, inline, &mut args)?.parse()?,
+            "--zdarzenia" => options.events = Some(take_value(flag, inline, &mut args)?.into()),
+            "--reguly" => options.rules = take_value(flag, inline, &mut args)?.parse()?,
+            "--stan" if inline.is_none() => options.state = true,
+            _ => return Err(format!("Nieznany argument: {arg}")),
+        }
+    }
+    Ok(options)
+}
+
+/// Replays the event log from [`Options::events`] onto `tasks`; without a log nothing changes.
+pub fn replay_events(options: &Options, tasks: Vec<Task>) -> Result<Replay, String> {
+    let Some(path) = &options.events else {
+        return Ok(Replay {
+            tasks,
+            ..Replay::default()
+        });
+    };
+    let log = fs::read_to_string(path)
+        .map_err(|err| format!("Nie mozna odczytac {}: {err}", path.display()))?;
+    Ok(workflow::replay(tasks, &log, &options.rules))
+}
+
+/// Output lines for `tasks` matching the query: the summary in the chosen format, or the
+/// tasks themselves as input lines with [`Options::state`].
+pub fn render(options: &Options, tasks: &[Task]) -> Vec<String> {
+    let tasks = options.query.filter(tasks);
+    if options.state {
+        return tasks.iter().map(Task::to_string).collect();
+    }
+    options
+        .format
+        .formatter()
+        .format(&summarize_by_status(&tasks))
+}
+
+/// Everything the program prints and the code it exits with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Outcome {
+    pub stdout: Vec<String>,
+    pub stderr: Vec<String>,
+    pub exit_code: i32,
+}
+
+/// The whole program: parses `args`, reads the tasks from `reader`, replays events, then
+/// renders the report for the valid tasks and lists every bad line and rejected event.
+///
+/// Exit code 1 means invalid lines, rejected events or no tasks at all; 2 means bad
+/// arguments or unreadable input or event log, in which case nothing is rendered.
+pub fn run_program<I, R>(args: I, reader: R) -> Outcome
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+    R: BufRead,
+{
+    let failure = |message: String| Outcome {
+        stderr: vec![message],
+        exit_code: 2,
+        ..Outcome::default()
+    };
+    let options = match parse_args(args) {
+        Ok(options) => options,
+        Err(message) => return failure(message),
+    };
+    let input = match read_input(reader) {
+        Ok(input) => input,
+        Err(err) => return failure(err.to_string()),
+    };
+
+    // Valid tasks are summarized even when other lines are broken; every error is reported.
+    let diagnosis = diagnose_tasks(&input);
+    let has_tasks = !diagnosis.tasks.is_empty();
+    let replay = match replay_events(&options, diagnosis.tasks) {
+        Ok(replay) => replay,
+        Err(message) => return failure(message),
+    };
+
+    let mut outcome = Outcome::default();
+    if has_tasks {
+        outcome.stdout = render(&options, &replay.tasks);
+    }
+    outcome
+        .stderr
+        .extend(diagnosis.errors.iter().map(LineError::to_string));
+    if !replay.rejected.is_empty() {
+        outcome
+            .stderr
+            .push(format!("Odrzucone zdarzenia ({}):", replay.rejected.len()));
+        outcome
+            .stderr
+            .extend(replay.rejected.iter().map(|rejected| rejected.to_string()));
+    }
+    if !has_tasks && diagnosis.errors.is_empty() {
+        outcome.stderr.push(TaskParseError::NoTasks.to_string());
+    }
+    if !outcome.stderr.is_empty() {
+        outcome.exit_code = 1;
+    }
+    outcome
 }
 
 /// Convenience API for tests that accepts input as a single string.
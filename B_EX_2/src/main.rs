@@ -1,20 +1,20 @@
+use std::env;
 use std::io::{self};
 
-use b_ex_2::run_from_reader;
+use b_ex_2::run_program;
 
 fn main() {
     let stdin = io::stdin();
     let handle = stdin.lock();
 
-    match run_from_reader(handle) {
-        Ok(lines) => {
-            for line in lines {
-                println!("{line}");
-            }
-        }
-        Err(err) => {
-            eprintln!("{err}");
-            std::process::exit(1);
-        }
+    let outcome = run_program(env::args().skip(1), handle);
+    for line in &outcome.stdout {
+        println!("{line}");
+    }
+    for line in &outcome.stderr {
+        eprintln!("{line}");
+    }
+    if outcome.exit_code != 0 {
+        std::process::exit(outcome.exit_code);
     }
 }
@@ -0,0 +1,181 @@
+//! Output formats for the status summary.
+//!
+//! Every format implements [`SummaryFormatter`]; [`OutputFormat`] names the built-in ones so the
+//! binary can pick one with `--format`.
+use std::fmt;
+use std::str::FromStr;
+
+use crate::domain::Priority;
+use crate::{format_summary, StatusSummary};
+
+/// Version of the JSON schema written by [`JsonFormat`]. Bump it on any incompatible change.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Turns summaries (one per status, in report order) into output lines.
+pub trait SummaryFormatter {
+    fn format(&self, summary: &[StatusSummary]) -> Vec<String>;
+}
+
+/// Current text format: `TODO: 2 (high: 1, medium: 1, low: 0)`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextFormat;
+
+impl SummaryFormatter for TextFormat {
+    fn format(&self, summary: &[StatusSummary]) -> Vec<String> {
+        format_summary(summary)
+    }
+}
+
+/// JSON document with a stable schema:
+///
+/// ```text
+/// {
+///   "schema_version": 1,
+///   "total": 4,
+///   "statuses": [
+///     {"status": "TODO", "total": 2, "priorities": {"high": 1, "medium": 1, "low": 0}},
+///     ...
+///   ]
+/// }
+/// ```
+///
+/// Every status and every priority is always present, in report order.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonFormat;
+
+impl SummaryFormatter for JsonFormat {
+    fn format(&self, summary: &[StatusSummary]) -> Vec<String> {
+        let total: usize = summary.iter().map(StatusSummary::total).sum();
+        let mut lines = vec![
+            "{".to_string(),
+            format!("  \"schema_version\": {JSON_SCHEMA_VERSION},"),
+            format!("  \"total\": {total},"),
+            "  \"statuses\": [".to_string(),
+        ];
+        for (index, entry) in summary.iter().enumerate() {
+            let priorities: Vec<String> = Priority::all()
+                .into_iter()
+                .map(|p| format!("\"{p}\": {}", count(entry, p)))
+                .collect();
+            let separator = if index + 1 < summary.len() { "," } else { "" };
+            lines.push(format!(
+                "    {{\"status\": \"{}\", \"total\": {}, \"priorities\": {{{}}}}}{separator}",
+                entry.status,
+                entry.total(),
+                priorities.join(", ")
+            ));
+        }
+        lines.push("  ]".to_string());
+        lines.push("}".to_string());
+        lines
+    }
+}
+
+/// CSV with a header row: `status,total,high,medium,low`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CsvFormat;
+
+impl SummaryFormatter for CsvFormat {
+    fn format(&self, summary: &[StatusSummary]) -> Vec<String> {
+        let mut header = vec!["status".to_string(), "total".to_string()];
+        header.extend(Priority::all().map(|p| p.to_string()));
+        let mut lines = vec![header.join(",")];
+        lines.extend(summary.iter().map(|entry| row(entry).join(",")));
+        lines
+    }
+}
+
+/// Markdown table with one row per status.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarkdownFormat;
+
+impl SummaryFormatter for MarkdownFormat {
+    fn format(&self, summary: &[StatusSummary]) -> Vec<String> {
+        let mut header = vec!["Status".to_string(), "Total".to_string()];
+        header.extend(Priority::all().map(|p| p.to_string()));
+        // Status left-aligned, counts right-aligned.
+        let mut alignment = vec!["---".to_string()];
+        alignment.resize(header.len(), "---:".to_string());
+
+        let table_row = |cells: &[String]| format!("| {} |", cells.join(" | "));
+        let mut lines = vec![table_row(&header), table_row(&alignment)];
+        lines.extend(summary.iter().map(|entry| table_row(&row(entry))));
+        lines
+    }
+}
+
+fn count(entry: &StatusSummary, priority: Priority) -> usize {
+    entry.counts.get(&priority).copied().unwrap_or(0)
+}
+
+/// Status, total and per-priority counts of one summary entry.
+fn row(entry: &StatusSummary) -> Vec<String> {
+    let mut cells = vec![entry.status.to_string(), entry.total().to_string()];
+    cells.extend(Priority::all().map(|p| count(entry, p).to_string()));
+    cells
+}
+
+/// Built-in output formats.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl OutputFormat {
+    pub fn all() -> [OutputFormat; 4] {
+        [
+            OutputFormat::Text,
+            OutputFormat::Json,
+            OutputFormat::Csv,
+            OutputFormat::Markdown,
+        ]
+    }
+
+    /// Name accepted by `--format`.
+    pub fn label(self) -> &'static str {
+        match self {
+            OutputFormat::Text => "tekst",
+            OutputFormat::Json => "json",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Markdown => "markdown",
+        }
+    }
+
+    pub fn formatter(self) -> &'static dyn SummaryFormatter {
+        match self {
+            OutputFormat::Text => &TextFormat,
+            OutputFormat::Json => &JsonFormat,
+            OutputFormat::Csv => &CsvFormat,
+            OutputFormat::Markdown => &MarkdownFormat,
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    /// Case-insensitive; also accepts `text` and `md`.
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "md" => Ok(OutputFormat::Markdown),
+            name => OutputFormat::all()
+                .into_iter()
+                .find(|format| format.label() == name)
+                .ok_or_else(|| {
+                    let names = OutputFormat::all().map(OutputFormat::label).join(", ");
+                    format!("Nieznany format: {raw} (dostepne: {names})")
+                }),
+        }
+    }
+}
@@ -0,0 +1,376 @@
+//! Filter queries over tasks, e.g. `status=TODO,IN_PROGRESS priority>=medium title~"deploy"`.
+//!
+//! A query is a whitespace-separated list of conditions that must all hold. Each condition is
+//! `field operator value`:
+//! - fields: `status`, `priority`, `due`, `title`, `assignee`, `tag`,
+//! - operators: `=` and `!=` (one value or a comma-separated list), `<`, `<=`, `>`, `>=`
+//!   (only for `status`, `priority` and `due`) and `~` (substring, only for text fields),
+//! - values: bare words or `"quoted strings"` (with `\"` and `\\` escapes).
+//!
+//! Comparisons follow the declared order of [`Status`] (`TODO < IN_PROGRESS < DONE`) and of
+//! [`Priority`] read from the lowest up, so `priority>=medium` keeps `medium` and `high`.
+//! Text comparisons ignore letter case. A task without an assignee or due date only
+//! matches `!=` conditions on that field.
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::domain::{DueDate, Priority, Status, Task, TaskParseError};
+
+/// Error returned when a query cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryError {
+    /// Condition without any of the operators (holds the condition).
+    MissingOperator(String),
+    /// Field name that is not supported.
+    UnknownField(String),
+    /// Operator that makes no sense for the field.
+    UnsupportedOperator { field: String, operator: String },
+    /// Value list given to an operator that takes a single value (holds the condition).
+    UnexpectedList(String),
+    /// Condition with an empty value or an empty list item (holds the condition).
+    EmptyValue(String),
+    /// Quoted value without the closing quote (holds the condition).
+    UnterminatedQuote(String),
+    /// Value that is not a valid priority, status or date.
+    InvalidValue(TaskParseError),
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryError::MissingOperator(term) => {
+                write!(f, "Brak operatora w warunku: {term}")
+            }
+            QueryError::UnknownField(field) => write!(
+                f,
+                "Nieznane pole zapytania: {field} (dostepne: status, priority, due, title, assignee, tag)"
+            ),
+            QueryError::UnsupportedOperator { field, operator } => {
+                write!(f, "Operator {operator} nie jest dozwolony dla pola {field}")
+            }
+            QueryError::UnexpectedList(term) => {
+                write!(f, "Lista wartosci jest dozwolona tylko z = i !=: {term}")
+            }
+            QueryError::EmptyValue(term) => write!(f, "Brak wartosci w warunku: {term}"),
+            QueryError::UnterminatedQuote(term) => {
+                write!(f, "Niezamkniety cudzyslow w warunku: {term}")
+            }
+            QueryError::InvalidValue(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+impl From<TaskParseError> for QueryError {
+    fn from(err: TaskParseError) -> Self {
+        QueryError::InvalidValue(err)
+    }
+}
+
+/// Comparison operator of a condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Contains,
+}
+
+impl Operator {
+    /// Operators in the order they are tried, so `<=` is matched before `<`.
+    const ALL: [Operator; 7] = [
+        Operator::NotEqual,
+        Operator::LessOrEqual,
+        Operator::GreaterOrEqual,
+        Operator::Equal,
+        Operator::Less,
+        Operator::Greater,
+        Operator::Contains,
+    ];
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Operator::Equal => "=",
+            Operator::NotEqual => "!=",
+            Operator::Less => "<",
+            Operator::LessOrEqual => "<=",
+            Operator::Greater => ">",
+            Operator::GreaterOrEqual => ">=",
+            Operator::Contains => "~",
+        }
+    }
+
+    fn is_ordering(self) -> bool {
+        matches!(
+            self,
+            Operator::Less | Operator::LessOrEqual | Operator::Greater | Operator::GreaterOrEqual
+        )
+    }
+
+    fn accepts(self, ordering: Ordering) -> bool {
+        match self {
+            Operator::Equal => ordering.is_eq(),
+            Operator::NotEqual => ordering.is_ne(),
+            Operator::Less => ordering.is_lt(),
+            Operator::LessOrEqual => ordering.is_le(),
+            Operator::Greater => ordering.is_gt(),
+            Operator::GreaterOrEqual => ordering.is_ge(),
+            Operator::Contains => false,
+        }
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// Condition on a field with ordered values (`status`, `priority`, `due`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Comparison<T> {
+    /// `=` with one or more values.
+    In(Vec<T>),
+    /// `!=` with one or more values.
+    NotIn(Vec<T>),
+    /// `<`, `<=`, `>` or `>=` with a single value.
+    Ordered(Operator, T),
+}
+
+impl<T: PartialEq> Comparison<T> {
+    /// Checks `value`, ordering values by `key`. A missing value only satisfies [`Comparison::NotIn`].
+    fn matches_by<K: Ord>(&self, value: Option<&T>, key: impl Fn(&T) -> K) -> bool {
+        match (self, value) {
+            (Comparison::In(values), Some(value)) => values.contains(value),
+            (Comparison::NotIn(values), Some(value)) => !values.contains(value),
+            (Comparison::NotIn(_), None) => true,
+            (Comparison::Ordered(operator, bound), Some(value)) => {
+                operator.accepts(key(value).cmp(&key(bound)))
+            }
+            (_, None) => false,
+        }
+    }
+}
+
+/// Condition on a text field (`title`, `assignee`, `tag`), compared without letter case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextMatch {
+    /// `=` with one or more values.
+    In(Vec<String>),
+    /// `!=` with one or more values.
+    NotIn(Vec<String>),
+    /// `~` with a single value.
+    Contains(String),
+}
+
+impl TextMatch {
+    fn matches_one(&self, text: &str) -> bool {
+        let text = text.to_lowercase();
+        match self {
+            TextMatch::In(values) => values.iter().any(|v| v.to_lowercase() == text),
+            TextMatch::NotIn(values) => values.iter().all(|v| v.to_lowercase() != text),
+            TextMatch::Contains(part) => text.contains(&part.to_lowercase()),
+        }
+    }
+
+    /// Checks a field holding several texts (tags): `!=` needs all of them to differ,
+    /// the other operators need at least one to match.
+    fn matches_any<'a>(&self, texts: impl IntoIterator<Item = &'a str>) -> bool {
+        let mut texts = texts.into_iter();
+        match self {
+            TextMatch::NotIn(_) => texts.all(|text| self.matches_one(text)),
+            _ => texts.any(|text| self.matches_one(text)),
+        }
+    }
+}
+
+/// Single condition of a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    Status(Comparison<Status>),
+    Priority(Comparison<Priority>),
+    Due(Comparison<DueDate>),
+    Title(TextMatch),
+    Assignee(TextMatch),
+    Tag(TextMatch),
+}
+
+impl Condition {
+    pub fn matches(&self, task: &Task) -> bool {
+        match self {
+            Condition::Status(cmp) => cmp.matches_by(Some(&task.status), |s| *s),
+            // Priorities are declared from the highest, so a higher priority compares greater
+            // once the order is reversed.
+            Condition::Priority(cmp) => cmp.matches_by(Some(&task.priority), |p| Reverse(*p)),
+            Condition::Due(cmp) => cmp.matches_by(task.due.as_ref(), |d| *d),
+            Condition::Title(text) => text.matches_one(&task.title),
+            Condition::Assignee(text) => text.matches_any(task.assignee.as_deref()),
+            Condition::Tag(text) => text.matches_any(task.tags.iter().map(String::as_str)),
+        }
+    }
+}
+
+/// Parsed query: all conditions must hold. An empty query matches every task.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Query {
+    pub conditions: Vec<Condition>,
+}
+
+impl Query {
+    pub fn matches(&self, task: &Task) -> bool {
+        self.conditions
+            .iter()
+            .all(|condition| condition.matches(task))
+    }
+
+    /// Tasks matching the query, in input order.
+    pub fn filter(&self, tasks: &[Task]) -> Vec<Task> {
+        tasks
+            .iter()
+            .filter(|task| self.matches(task))
+            .cloned()
+            .collect()
+    }
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let conditions = split_terms(input)?
+            .iter()
+            .map(|term| parse_condition(term))
+            .collect::<Result<_, _>>()?;
+        Ok(Query { conditions })
+    }
+}
+
+/// Splits the query on whitespace outside quotes.
+fn split_terms(input: &str) -> Result<Vec<String>, QueryError> {
+    let mut terms = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => quoted = !quoted,
+            '\\' if quoted => {
+                current.push(c);
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+                continue;
+            }
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    terms.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if quoted {
+        return Err(QueryError::UnterminatedQuote(current));
+    }
+    if !current.is_empty() {
+        terms.push(current);
+    }
+    Ok(terms)
+}
+
+/// Splits a value on commas outside quotes and removes the quotes and escapes.
+fn split_values(raw: &str, term: &str) -> Result<Vec<String>, QueryError> {
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => quoted = !quoted,
+            '\\' if quoted => current.extend(chars.next()),
+            ',' if !quoted => values.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    values.push(current);
+    if values.iter().any(String::is_empty) {
+        return Err(QueryError::EmptyValue(term.to_string()));
+    }
+    Ok(values)
+}
+
+fn parse_condition(term: &str) -> Result<Condition, QueryError> {
+    let start = term
+        .find(['=', '!', '<', '>', '~'])
+        .ok_or_else(|| QueryError::MissingOperator(term.to_string()))?;
+    let (field, rest) = term.split_at(start);
+    let operator = Operator::ALL
+        .into_iter()
+        .find(|op| rest.starts_with(op.symbol()))
+        .ok_or_else(|| QueryError::MissingOperator(term.to_string()))?;
+    let values = split_values(&rest[operator.symbol().len()..], term)?;
+    if values.len() > 1 && !matches!(operator, Operator::Equal | Operator::NotEqual) {
+        return Err(QueryError::UnexpectedList(term.to_string()));
+    }
+
+    match field.to_ascii_lowercase().as_str() {
+        "status" => comparison(field, operator, &values).map(Condition::Status),
+        "priority" => comparison(field, operator, &values).map(Condition::Priority),
+        "due" => comparison(field, operator, &values).map(Condition::Due),
+        "title" => text_match(field, operator, values).map(Condition::Title),
+        "assignee" => text_match(field, operator, values).map(Condition::Assignee),
+        "tag" => text_match(field, operator, values).map(Condition::Tag),
+        _ => Err(QueryError::UnknownField(field.to_string())),
+    }
+}
+
+fn unsupported(field: &str, operator: Operator) -> QueryError {
+    QueryError::UnsupportedOperator {
+        field: field.to_string(),
+        operator: operator.to_string(),
+    }
+}
+
+/// Builds a comparison on an ordered field; `~` is not supported there.
+fn comparison<T>(
+    field: &str,
+    operator: Operator,
+    values: &[String],
+) -> Result<Comparison<T>, QueryError>
+where
+    T: FromStr<Err = TaskParseError>,
+{
+    if operator == Operator::Contains {
+        return Err(unsupported(field, operator));
+    }
+    let mut parsed = values
+        .iter()
+        .map(|value| value.parse::<T>())
+        .collect::<Result<Vec<T>, _>>()?;
+    Ok(match operator {
+        Operator::Equal => Comparison::In(parsed),
+        Operator::NotEqual => Comparison::NotIn(parsed),
+        _ => Comparison::Ordered(operator, parsed.remove(0)),
+    })
+}
+
+/// Builds a match on a text field; ordering operators are not supported there.
+fn text_match(
+    field: &str,
+    operator: Operator,
+    mut values: Vec<String>,
+) -> Result<TextMatch, QueryError> {
+    Ok(match operator {
+        _ if operator.is_ordering() => return Err(unsupported(field, operator)),
+        Operator::Equal => TextMatch::In(values),
+        Operator::NotEqual => TextMatch::NotIn(values),
+        _ => TextMatch::Contains(values.remove(0)),
+    })
+}
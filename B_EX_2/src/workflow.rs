@@ -0,0 +1,347 @@
+//! Replaying a log of workflow events onto a task list.
+//!
+//! Each non-empty line of the log is one event:
+//! - `move "title" IN_PROGRESS` changes the status of the task,
+//! - `reprioritize "title" high` changes its priority,
+//! - a trailing `force` (`move "title" DONE force`) skips the transition rules.
+//!
+//! Titles are matched exactly and may be written without quotes when they are a single word.
+//! Status changes must follow [`TransitionRules`]; events that cannot be applied are collected
+//! in a report instead of stopping the replay.
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::domain::{Priority, Status, Task, TaskParseError};
+
+/// Error for an event line that cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventParseError {
+    /// First word is neither `move` nor `reprioritize`.
+    UnknownCommand(String),
+    /// Event without a title, or a title with an unclosed quote (holds the event).
+    InvalidTitle(String),
+    /// Event without the new status or priority (holds the event).
+    MissingValue(String),
+    /// Unknown status or priority.
+    InvalidValue(TaskParseError),
+    /// Extra word after the event other than `force`.
+    UnexpectedToken(String),
+}
+
+impl fmt::Display for EventParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventParseError::UnknownCommand(command) => write!(f, "unknown command \"{command}\""),
+            EventParseError::InvalidTitle(event) => write!(f, "invalid title in \"{event}\""),
+            EventParseError::MissingValue(event) => write!(f, "missing value in \"{event}\""),
+            EventParseError::InvalidValue(TaskParseError::InvalidStatus(value)) => {
+                write!(f, "unknown status \"{value}\"")
+            }
+            EventParseError::InvalidValue(TaskParseError::InvalidPriority(value)) => {
+                write!(f, "unknown priority \"{value}\"")
+            }
+            EventParseError::InvalidValue(err) => write!(f, "{err}"),
+            EventParseError::UnexpectedToken(token) => write!(f, "unexpected \"{token}\""),
+        }
+    }
+}
+
+impl std::error::Error for EventParseError {}
+
+impl From<TaskParseError> for EventParseError {
+    fn from(err: TaskParseError) -> Self {
+        EventParseError::InvalidValue(err)
+    }
+}
+
+/// Change requested by an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Move(Status),
+    Reprioritize(Priority),
+}
+
+/// Single entry of the event log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub title: String,
+    pub command: Command,
+    /// Skips the transition rules (`force`).
+    pub forced: bool,
+}
+
+impl fmt::Display for Event {
+    /// Canonical form of the event, e.g. `move "Deploy api" DONE force`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let title = self.title.replace('\\', "\\\\").replace('"', "\\\"");
+        match self.command {
+            Command::Move(status) => write!(f, "move \"{title}\" {status}")?,
+            Command::Reprioritize(priority) => write!(f, "reprioritize \"{title}\" {priority}")?,
+        }
+        if self.forced {
+            write!(f, " force")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Event {
+    type Err = EventParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = line.trim();
+        let (word, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let is_move = match word.to_ascii_lowercase().as_str() {
+            "move" => true,
+            "reprioritize" => false,
+            _ => return Err(EventParseError::UnknownCommand(word.into())),
+        };
+        let (title, rest) = split_title(rest.trim_start())
+            .ok_or_else(|| EventParseError::InvalidTitle(line.into()))?;
+        let mut words = rest.split_whitespace();
+        let value = words
+            .next()
+            .ok_or_else(|| EventParseError::MissingValue(line.into()))?;
+        let command = if is_move {
+            Command::Move(value.parse()?)
+        } else {
+            Command::Reprioritize(value.parse()?)
+        };
+        let forced = match words.next() {
+            None => false,
+            Some(word) if word.eq_ignore_ascii_case("force") => true,
+            Some(word) => return Err(EventParseError::UnexpectedToken(word.into())),
+        };
+        if let Some(word) = words.next() {
+            return Err(EventParseError::UnexpectedToken(word.into()));
+        }
+        Ok(Event {
+            title,
+            command,
+            forced,
+        })
+    }
+}
+
+/// Splits off the title: a `"quoted string"` (with `\"` and `\\` escapes) or a single word.
+fn split_title(text: &str) -> Option<(String, &str)> {
+    let Some(quoted) = text.strip_prefix('"') else {
+        let end = text.find(char::is_whitespace).unwrap_or(text.len());
+        return (end > 0).then(|| (text[..end].to_string(), &text[end..]));
+    };
+    let mut title = String::new();
+    let mut chars = quoted.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => title.extend(chars.next().map(|(_, next)| next)),
+            '"' => return (!title.is_empty()).then(|| (title, &quoted[index + 1..])),
+            _ => title.push(c),
+        }
+    }
+    None
+}
+
+/// Status changes allowed without `force`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionRules {
+    allowed: BTreeSet<(Status, Status)>,
+}
+
+impl Default for TransitionRules {
+    /// One step along the business order, forwards or back: `TODO <-> IN_PROGRESS <-> DONE`.
+    fn default() -> Self {
+        let statuses = Status::all();
+        let mut rules = TransitionRules::none();
+        for pair in statuses.windows(2) {
+            rules.allow(pair[0], pair[1]);
+            rules.allow(pair[1], pair[0]);
+        }
+        rules
+    }
+}
+
+impl TransitionRules {
+    /// Rules that reject every change unless forced.
+    pub fn none() -> Self {
+        TransitionRules {
+            allowed: BTreeSet::new(),
+        }
+    }
+
+    pub fn allow(&mut self, from: Status, to: Status) -> &mut Self {
+        self.allowed.insert((from, to));
+        self
+    }
+
+    pub fn forbid(&mut self, from: Status, to: Status) -> &mut Self {
+        self.allowed.remove(&(from, to));
+        self
+    }
+
+    pub fn allows(&self, from: Status, to: Status) -> bool {
+        self.allowed.contains(&(from, to))
+    }
+}
+
+impl fmt::Display for TransitionRules {
+    /// Same format as accepted by [`TransitionRules::from_str`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pairs: Vec<String> = self
+            .allowed
+            .iter()
+            .map(|(from, to)| format!("{from}->{to}"))
+            .collect();
+        f.write_str(&pairs.join(","))
+    }
+}
+
+impl FromStr for TransitionRules {
+    type Err = String;
+
+    /// Comma-separated list of allowed changes, e.g. `TODO->IN_PROGRESS,IN_PROGRESS->DONE`.
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let mut rules = TransitionRules::none();
+        for pair in raw
+            .split(',')
+            .map(str::trim)
+            .filter(|pair| !pair.is_empty())
+        {
+            let (from, to) = pair.split_once("->").ok_or_else(|| {
+                format!("Niepoprawne przejscie: {pair} (oczekiwano STATUS->STATUS)")
+            })?;
+            let status = |raw: &str| raw.parse::<Status>().map_err(|err| err.to_string());
+            rules.allow(status(from)?, status(to)?);
+        }
+        Ok(rules)
+    }
+}
+
+/// Why an event was not applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RejectReason {
+    Invalid(EventParseError),
+    UnknownTask(String),
+    /// Several tasks share the title (holds the title and the number of tasks).
+    AmbiguousTask(String, usize),
+    IllegalTransition {
+        from: Status,
+        to: Status,
+    },
+    /// The task already has the requested status or priority.
+    NoChange,
+}
+
+impl fmt::Display for RejectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RejectReason::Invalid(err) => write!(f, "{err}"),
+            RejectReason::UnknownTask(title) => write!(f, "unknown task \"{title}\""),
+            RejectReason::AmbiguousTask(title, count) => {
+                write!(f, "{count} tasks are titled \"{title}\"")
+            }
+            RejectReason::IllegalTransition { from, to } => {
+                write!(
+                    f,
+                    "transition {from} -> {to} is not allowed (add force to override)"
+                )
+            }
+            RejectReason::NoChange => write!(f, "nothing to change"),
+        }
+    }
+}
+
+/// Event that was applied, with the state of the task just before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedEvent {
+    pub line: usize,
+    pub event: Event,
+    pub previous_status: Status,
+    pub previous_priority: Priority,
+}
+
+/// Event line that was not applied, with its 1-based line number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedEvent {
+    pub line: usize,
+    pub text: String,
+    pub reason: RejectReason,
+}
+
+impl fmt::Display for RejectedEvent {
+    /// Compiler-style line, e.g. `line 3: transition TODO -> DONE is not allowed (...)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+/// Outcome of [`replay`]: the final tasks plus what happened to every event.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Replay {
+    pub tasks: Vec<Task>,
+    pub applied: Vec<AppliedEvent>,
+    pub rejected: Vec<RejectedEvent>,
+}
+
+/// Applies the events from `log` to `tasks` in order, skipping the ones that break `rules`.
+pub fn replay(tasks: Vec<Task>, log: &str, rules: &TransitionRules) -> Replay {
+    let mut replay = Replay {
+        tasks,
+        ..Replay::default()
+    };
+    for (index, text) in log.lines().enumerate() {
+        if text.trim().is_empty() {
+            continue;
+        }
+        let line = index + 1;
+        let applied = text
+            .parse::<Event>()
+            .map_err(RejectReason::Invalid)
+            .and_then(|event| apply(&mut replay.tasks, event, line, rules));
+        match applied {
+            Ok(applied) => replay.applied.push(applied),
+            Err(reason) => replay.rejected.push(RejectedEvent {
+                line,
+                text: text.trim().to_string(),
+                reason,
+            }),
+        }
+    }
+    replay
+}
+
+fn apply(
+    tasks: &mut [Task],
+    event: Event,
+    line: usize,
+    rules: &TransitionRules,
+) -> Result<AppliedEvent, RejectReason> {
+    let mut matching = tasks.iter_mut().filter(|task| task.title == event.title);
+    let task = matching
+        .next()
+        .ok_or_else(|| RejectReason::UnknownTask(event.title.clone()))?;
+    let others = matching.count();
+    if others > 0 {
+        return Err(RejectReason::AmbiguousTask(event.title.clone(), others + 1));
+    }
+
+    let applied = AppliedEvent {
+        line,
+        previous_status: task.status,
+        previous_priority: task.priority,
+        event,
+    };
+    match applied.event.command {
+        Command::Move(to) if to == task.status => return Err(RejectReason::NoChange),
+        Command::Move(to) if !applied.event.forced && !rules.allows(task.status, to) => {
+            return Err(RejectReason::IllegalTransition {
+                from: task.status,
+                to,
+            })
+        }
+        Command::Move(to) => task.status = to,
+        Command::Reprioritize(to) if to == task.priority => return Err(RejectReason::NoChange),
+        Command::Reprioritize(to) => task.priority = to,
+    }
+    Ok(applied)
+}
@@ -12,10 +12,10 @@ fn expect_counts(summary: &b_ex_2::StatusSummary, high: usize, medium: usize, lo
 #[test]
 fn summarize_counts_and_total_per_status() {
     let tasks = vec![
-        b_ex_2::domain::Task { title: "A".into(), priority: Priority::High, status: Status::Todo },
-        b_ex_2::domain::Task { title: "B".into(), priority: Priority::Medium, status: Status::Done },
-        b_ex_2::domain::Task { title: "C".into(), priority: Priority::Low, status: Status::InProgress },
-        b_ex_2::domain::Task { title: "D".into(), priority: Priority::High, status: Status::Done },
+        b_ex_2::domain::Task::new("A", Priority::High, Status::Todo),
+        b_ex_2::domain::Task::new("B", Priority::Medium, Status::Done),
+        b_ex_2::domain::Task::new("C", Priority::Low, Status::InProgress),
+        b_ex_2::domain::Task::new("D", Priority::High, Status::Done),
     ];
 
     let summary = summarize_by_status(&tasks);
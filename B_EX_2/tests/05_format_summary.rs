@@ -4,10 +4,10 @@ use b_ex_2::domain::{Priority, Status};
 #[test]
 fn format_summary_matches_expected_lines_and_order() {
     let tasks = vec![
-        b_ex_2::domain::Task { title: "Ship".into(), priority: Priority::High, status: Status::Todo },
-        b_ex_2::domain::Task { title: "Docs".into(), priority: Priority::Medium, status: Status::Done },
-        b_ex_2::domain::Task { title: "Pair".into(), priority: Priority::Low, status: Status::InProgress },
-        b_ex_2::domain::Task { title: "Refactor".into(), priority: Priority::High, status: Status::Done },
+        b_ex_2::domain::Task::new("Ship", Priority::High, Status::Todo),
+        b_ex_2::domain::Task::new("Docs", Priority::Medium, Status::Done),
+        b_ex_2::domain::Task::new("Pair", Priority::Low, Status::InProgress),
+        b_ex_2::domain::Task::new("Refactor", Priority::High, Status::Done),
     ];
     let summary = summarize_by_status(&tasks);
     let lines = format_summary(&summary);
@@ -22,7 +22,7 @@ fn format_summary_matches_expected_lines_and_order() {
 #[test]
 fn format_summary_includes_zeros_for_missing_priorities() {
     let tasks = vec![
-        b_ex_2::domain::Task { title: "Only".into(), priority: Priority::Medium, status: Status::Todo },
+        b_ex_2::domain::Task::new("Only", Priority::Medium, Status::Todo),
     ];
     let summary = summarize_by_status(&tasks);
     let lines = format_summary(&summary);
@@ -0,0 +1,73 @@
+use std::str::FromStr;
+use b_ex_2::domain::{DueDate, Priority, Status, Task, TaskParseError};
+
+#[test]
+fn three_field_lines_have_no_optional_fields() {
+    let t = Task::from_str("Ship layout | high | todo").expect("should parse");
+    assert_eq!(t, Task::new("Ship layout", Priority::High, Status::Todo));
+    assert_eq!(t.assignee, None);
+    assert_eq!(t.due, None);
+    assert!(t.tags.is_empty());
+}
+
+#[test]
+fn optional_fields_are_parsed_in_any_order() {
+    let t = Task::from_str("Deploy | medium | in_progress | @ania | 2024-02-29 | #ops #release")
+        .expect("should parse");
+    assert_eq!(t.assignee.as_deref(), Some("ania"));
+    assert_eq!(t.due, Some(DueDate { year: 2024, month: 2, day: 29 }));
+    assert_eq!(t.tags, vec!["ops", "release"]);
+
+    // Kolejność pól opcjonalnych nie ma znaczenia.
+    let other = Task::from_str("Deploy | medium | in_progress | #ops #release | 2024-02-29 | @ania")
+        .expect("should parse");
+    assert_eq!(other, t);
+    assert_eq!(t.due.unwrap().to_string(), "2024-02-29");
+}
+
+#[test]
+fn invalid_optional_values_have_their_own_errors() {
+    let err = Task::from_str("A | low | done | @").expect_err("empty assignee");
+    assert_eq!(err, TaskParseError::InvalidAssignee("@".into()));
+
+    let err = Task::from_str("A | low | done | @jan kowalski").expect_err("assignee with space");
+    assert_eq!(err, TaskParseError::InvalidAssignee("@jan kowalski".into()));
+
+    let err = Task::from_str("A | low | done | 2023-02-29").expect_err("not a leap year");
+    assert_eq!(err, TaskParseError::InvalidDueDate("2023-02-29".into()));
+
+    let err = Task::from_str("A | low | done | 2024-1-5").expect_err("not ISO");
+    assert_eq!(err, TaskParseError::InvalidDueDate("2024-1-5".into()));
+
+    let err = Task::from_str("A | low | done | #ops release").expect_err("tag without #");
+    assert_eq!(err, TaskParseError::InvalidTag("release".into()));
+
+    let err = Task::from_str("A | low | done | #ops #").expect_err("empty tag");
+    assert_eq!(err, TaskParseError::InvalidTag("#".into()));
+}
+
+#[test]
+fn unknown_or_repeated_optional_fields_are_invalid_format() {
+    let err = Task::from_str("A | low | done | later").expect_err("unknown field");
+    assert_eq!(err, TaskParseError::InvalidFormat("A | low | done | later".into()));
+
+    let err = Task::from_str("A | low | done | @ania | @olek").expect_err("repeated field");
+    assert_eq!(err, TaskParseError::InvalidFormat("A | low | done | @ania | @olek".into()));
+
+    // Kształt linii jest sprawdzany przed wartościami pól.
+    let err = Task::from_str("A | urgent | done | later").expect_err("format checked first");
+    assert!(matches!(err, TaskParseError::InvalidFormat(_)));
+}
+
+#[test]
+fn optional_error_messages() {
+    assert_eq!(
+        TaskParseError::InvalidAssignee("@".into()).to_string(),
+        "Niepoprawna osoba odpowiedzialna: @"
+    );
+    assert_eq!(
+        TaskParseError::InvalidDueDate("2024-13-01".into()).to_string(),
+        "Niepoprawny termin: 2024-13-01"
+    );
+    assert_eq!(TaskParseError::InvalidTag("x".into()).to_string(), "Niepoprawny tag: x");
+}
@@ -0,0 +1,57 @@
+use b_ex_2::diagnose_tasks;
+use b_ex_2::domain::{Task, TaskParseError};
+
+#[test]
+fn diagnose_collects_every_error_with_line_numbers() {
+    // Numeracja linii liczy także puste linie.
+    let input = "A | high | todo\n\nB | urgent | done\nbroken\nC | low | done\n";
+    let diagnosis = diagnose_tasks(input);
+
+    let titles: Vec<&str> = diagnosis.tasks.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["A", "C"]);
+
+    assert_eq!(diagnosis.errors.len(), 2);
+    assert_eq!(diagnosis.errors[0].line, 3);
+    assert_eq!(diagnosis.errors[0].error, TaskParseError::InvalidPriority("urgent".into()));
+    assert_eq!(diagnosis.errors[0].field(), Some("priority"));
+    assert_eq!(diagnosis.errors[1].line, 4);
+    assert_eq!(diagnosis.errors[1].field(), None);
+}
+
+#[test]
+fn diagnose_reports_all_bad_fields_of_one_line() {
+    let diagnosis = diagnose_tasks("X | urgent | paused | 2024-02-30\n");
+    assert!(diagnosis.tasks.is_empty());
+    let fields: Vec<_> = diagnosis.errors.iter().map(|e| (e.line, e.field())).collect();
+    assert_eq!(
+        fields,
+        vec![(1, Some("priority")), (1, Some("status")), (1, Some("due date"))]
+    );
+}
+
+#[test]
+fn diagnostics_are_compiler_style() {
+    let input = "ok | low | done\n".repeat(13) + "x | urgent | todo\ny | low | paused\nz\n";
+    let lines: Vec<String> = diagnose_tasks(&input).errors.iter().map(|e| e.to_string()).collect();
+    assert_eq!(lines, vec![
+        "line 14: unknown priority \"urgent\"",
+        "line 15: unknown status \"paused\"",
+        "line 16: invalid line format \"z\"",
+    ]);
+}
+
+#[test]
+fn parse_line_and_from_str_agree_on_first_error() {
+    let line = "X | urgent | paused";
+    let errors = Task::parse_line(line).expect_err("two bad fields");
+    assert_eq!(errors.len(), 2);
+    assert_eq!(line.parse::<Task>().expect_err("first error"), errors[0]);
+}
+
+#[test]
+fn diagnose_without_errors_matches_parse_tasks() {
+    let input = "A | high | todo\nB | low | done | @ola\n";
+    let diagnosis = diagnose_tasks(input);
+    assert!(diagnosis.errors.is_empty());
+    assert_eq!(diagnosis.tasks, b_ex_2::parse_tasks(input).unwrap());
+}
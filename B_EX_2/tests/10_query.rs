@@ -0,0 +1,88 @@
+use b_ex_2::domain::{Priority, Status, Task, TaskParseError};
+use b_ex_2::query::{Comparison, Condition, Operator, Query, QueryError, TextMatch};
+use b_ex_2::{parse_args, parse_tasks, run_program};
+
+fn tasks() -> Vec<Task> {
+    parse_tasks(
+        "Deploy api | high | todo | @ania | #ops\n\
+         Write docs | low | in_progress\n\
+         Deploy web | medium | in_progress | 2024-06-01\n\
+         Fix bug | high | done | #ops #bug\n",
+    )
+    .unwrap()
+}
+
+fn titles(query: &str) -> Vec<String> {
+    let query: Query = query.parse().expect("query should parse");
+    query.filter(&tasks()).into_iter().map(|t| t.title).collect()
+}
+
+#[test]
+fn query_is_parsed_into_conditions() {
+    let query: Query = "status=TODO,IN_PROGRESS priority>=medium title~\"deploy\"".parse().unwrap();
+    assert_eq!(query.conditions, vec![
+        Condition::Status(Comparison::In(vec![Status::Todo, Status::InProgress])),
+        Condition::Priority(Comparison::Ordered(Operator::GreaterOrEqual, Priority::Medium)),
+        Condition::Title(TextMatch::Contains("deploy".into())),
+    ]);
+}
+
+#[test]
+fn conditions_are_combined_with_and() {
+    assert_eq!(
+        titles("status=TODO,IN_PROGRESS priority>=medium title~\"deploy\""),
+        vec!["Deploy api", "Deploy web"]
+    );
+    assert_eq!(titles(""), vec!["Deploy api", "Write docs", "Deploy web", "Fix bug"]);
+}
+
+#[test]
+fn priority_and_status_comparisons_follow_business_order() {
+    // Wyższy priorytet jest "większy".
+    assert_eq!(titles("priority>medium"), vec!["Deploy api", "Fix bug"]);
+    assert_eq!(titles("priority<=medium"), vec!["Write docs", "Deploy web"]);
+    assert_eq!(titles("status<DONE priority!=low"), vec!["Deploy api", "Deploy web"]);
+    assert_eq!(titles("status>=in_progress"), vec!["Write docs", "Deploy web", "Fix bug"]);
+}
+
+#[test]
+fn text_fields_and_missing_values() {
+    assert_eq!(titles("title=\"fix BUG\""), vec!["Fix bug"]);
+    assert_eq!(titles("tag=OPS"), vec!["Deploy api", "Fix bug"]);
+    assert_eq!(titles("tag!=bug"), vec!["Deploy api", "Write docs", "Deploy web"]);
+    assert_eq!(titles("assignee=ania"), vec!["Deploy api"]);
+    // Brak terminu spełnia tylko warunek `!=`.
+    assert_eq!(titles("due<2025-01-01"), vec!["Deploy web"]);
+    assert_eq!(titles("due!=2024-06-01"), vec!["Deploy api", "Write docs", "Fix bug"]);
+}
+
+#[test]
+fn query_errors_are_readable() {
+    let err = |q: &str| q.parse::<Query>().expect_err("query should fail").to_string();
+    assert_eq!(err("status~todo"), "Operator ~ nie jest dozwolony dla pola status");
+    assert_eq!(err("title>a"), "Operator > nie jest dozwolony dla pola title");
+    assert_eq!(err("status"), "Brak operatora w warunku: status");
+    assert_eq!(err("status="), "Brak wartosci w warunku: status=");
+    assert_eq!(err("title~\"abc"), "Niezamkniety cudzyslow w warunku: title~\"abc");
+    assert_eq!(err("priority<high,low"), "Lista wartosci jest dozwolona tylko z = i !=: priority<high,low");
+    assert!(err("owner=ania").starts_with("Nieznane pole zapytania: owner"));
+
+    let parsed = "priority=urgent".parse::<Query>();
+    assert_eq!(parsed, Err(QueryError::InvalidValue(TaskParseError::InvalidPriority("urgent".into()))));
+}
+
+#[test]
+fn query_is_a_binary_argument() {
+    let input = "A | high | todo\nB | low | todo\nC | high | done\n";
+    let outcome = run_program(["--zapytanie", "priority=high"], input.as_bytes());
+    assert_eq!(outcome.exit_code, 0);
+    assert_eq!(outcome.stdout, vec![
+        "TODO: 1 (high: 1, medium: 0, low: 0)",
+        "IN_PROGRESS: 0 (high: 0, medium: 0, low: 0)",
+        "DONE: 1 (high: 1, medium: 0, low: 0)",
+    ]);
+
+    assert!(parse_args(["--zapytanie=status=done"]).is_ok());
+    assert_eq!(parse_args(["--zapytanie"]).unwrap_err(), "Brak wartosci dla --zapytanie");
+    assert_eq!(parse_args(["--inne"]).unwrap_err(), "Nieznany argument: --inne");
+}
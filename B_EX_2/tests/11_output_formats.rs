@@ -0,0 +1,76 @@
+use b_ex_2::domain::{Priority, Status, Task};
+use b_ex_2::output::{OutputFormat, SummaryFormatter, TextFormat};
+use b_ex_2::{format_summary, parse_args, run_program, summarize_by_status, StatusSummary};
+
+fn summary() -> Vec<StatusSummary> {
+    summarize_by_status(&[
+        Task::new("Ship", Priority::High, Status::Todo),
+        Task::new("Plan", Priority::Medium, Status::Todo),
+        Task::new("Docs", Priority::Low, Status::Done),
+    ])
+}
+
+fn render(format: OutputFormat) -> Vec<String> {
+    format.formatter().format(&summary())
+}
+
+#[test]
+fn text_format_is_the_default_and_matches_format_summary() {
+    assert_eq!(OutputFormat::default(), OutputFormat::Text);
+    assert_eq!(render(OutputFormat::Text), format_summary(&summary()));
+    assert_eq!(TextFormat.format(&summary())[0], "TODO: 2 (high: 1, medium: 1, low: 0)");
+}
+
+#[test]
+fn json_format_has_stable_schema() {
+    assert_eq!(render(OutputFormat::Json), vec![
+        "{",
+        "  \"schema_version\": 1,",
+        "  \"total\": 3,",
+        "  \"statuses\": [",
+        "    {\"status\": \"TODO\", \"total\": 2, \"priorities\": {\"high\": 1, \"medium\": 1, \"low\": 0}},",
+        "    {\"status\": \"IN_PROGRESS\", \"total\": 0, \"priorities\": {\"high\": 0, \"medium\": 0, \"low\": 0}},",
+        "    {\"status\": \"DONE\", \"total\": 1, \"priorities\": {\"high\": 0, \"medium\": 0, \"low\": 1}}",
+        "  ]",
+        "}",
+    ]);
+}
+
+#[test]
+fn csv_and_markdown_formats() {
+    assert_eq!(render(OutputFormat::Csv), vec![
+        "status,total,high,medium,low",
+        "TODO,2,1,1,0",
+        "IN_PROGRESS,0,0,0,0",
+        "DONE,1,0,0,1",
+    ]);
+    assert_eq!(render(OutputFormat::Markdown), vec![
+        "| Status | Total | high | medium | low |",
+        "| --- | ---: | ---: | ---: | ---: |",
+        "| TODO | 2 | 1 | 1 | 0 |",
+        "| IN_PROGRESS | 0 | 0 | 0 | 0 |",
+        "| DONE | 1 | 0 | 0 | 1 |",
+    ]);
+}
+
+#[test]
+fn format_names_round_trip() {
+    for format in OutputFormat::all() {
+        assert_eq!(format.label().parse::<OutputFormat>(), Ok(format));
+    }
+    assert_eq!("MD".parse::<OutputFormat>(), Ok(OutputFormat::Markdown));
+    assert_eq!("text".parse::<OutputFormat>(), Ok(OutputFormat::Text));
+    assert_eq!(
+        "xml".parse::<OutputFormat>().unwrap_err(),
+        "Nieznany format: xml (dostepne: tekst, json, csv, markdown)"
+    );
+}
+
+#[test]
+fn format_is_selected_from_arguments() {
+    let args = ["--format", "csv", "--zapytanie", "status=done"];
+    assert_eq!(parse_args(args).unwrap().format, OutputFormat::Csv);
+    let lines = run_program(args, "A | high | done\nB | low | todo\n".as_bytes()).stdout;
+    assert_eq!(lines[1], "TODO,0,0,0,0");
+    assert_eq!(lines[3], "DONE,1,1,0,0");
+}
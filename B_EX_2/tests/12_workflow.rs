@@ -0,0 +1,116 @@
+use std::str::FromStr;
+use b_ex_2::domain::{Priority, Status, Task, TaskParseError};
+use b_ex_2::workflow::{replay, Command, Event, EventParseError, RejectReason, TransitionRules};
+use b_ex_2::{parse_args, parse_tasks, render};
+
+fn tasks() -> Vec<Task> {
+    parse_tasks("Deploy api | high | todo | @ania | #ops\nWrite docs | low | todo\nFix bug | medium | in_progress\n")
+        .unwrap()
+}
+
+#[test]
+fn events_are_parsed_from_log_lines() {
+    let event = Event::from_str("move \"Deploy api\" in_progress").unwrap();
+    assert_eq!(event, Event { title: "Deploy api".into(), command: Command::Move(Status::InProgress), forced: false });
+
+    let event = Event::from_str("reprioritize Docs HIGH force").unwrap();
+    assert_eq!(event.title, "Docs");
+    assert_eq!(event.command, Command::Reprioritize(Priority::High));
+    assert!(event.forced);
+    assert_eq!(event.to_string(), "reprioritize \"Docs\" high force");
+
+    let event = Event::from_str(r#"move "Say \"hi\"" DONE"#).unwrap();
+    assert_eq!(event.title, "Say \"hi\"");
+    assert_eq!(event.to_string().parse::<Event>().unwrap(), event);
+}
+
+#[test]
+fn invalid_event_lines() {
+    let err = |line: &str| Event::from_str(line).expect_err("event should fail");
+    assert_eq!(err("jump \"A\" DONE"), EventParseError::UnknownCommand("jump".into()));
+    assert_eq!(err("move \"A DONE"), EventParseError::InvalidTitle("move \"A DONE".into()));
+    assert_eq!(err("move \"A\""), EventParseError::MissingValue("move \"A\"".into()));
+    assert_eq!(err("move A paused"), EventParseError::InvalidValue(TaskParseError::InvalidStatus("paused".into())));
+    assert_eq!(err("move A DONE now"), EventParseError::UnexpectedToken("now".into()));
+}
+
+#[test]
+fn default_rules_allow_single_steps_only() {
+    let rules = TransitionRules::default();
+    assert!(rules.allows(Status::Todo, Status::InProgress));
+    assert!(rules.allows(Status::InProgress, Status::Done));
+    assert!(rules.allows(Status::Done, Status::InProgress));
+    assert!(!rules.allows(Status::Todo, Status::Done));
+    assert!(!rules.allows(Status::Done, Status::Todo));
+    assert_eq!(rules.to_string(), "TODO->IN_PROGRESS,IN_PROGRESS->TODO,IN_PROGRESS->DONE,DONE->IN_PROGRESS");
+    assert_eq!(rules.to_string().parse::<TransitionRules>(), Ok(rules));
+}
+
+#[test]
+fn replay_applies_legal_events_and_reports_rejected_ones() {
+    let log = "move \"Deploy api\" IN_PROGRESS\n\
+               move \"Write docs\" DONE\n\
+               \n\
+               move \"Deploy api\" DONE\n\
+               move \"Write docs\" DONE force\n\
+               reprioritize \"Fix bug\" medium\n\
+               reprioritize Ghost low\n";
+    let result = replay(tasks(), log, &TransitionRules::default());
+
+    let states: Vec<(Status, Priority)> = result.tasks.iter().map(|t| (t.status, t.priority)).collect();
+    assert_eq!(states, vec![
+        (Status::Done, Priority::High),
+        (Status::Done, Priority::Low),
+        (Status::InProgress, Priority::Medium),
+    ]);
+
+    let applied: Vec<usize> = result.applied.iter().map(|a| a.line).collect();
+    assert_eq!(applied, vec![1, 4, 5]);
+    assert_eq!(result.applied[1].previous_status, Status::InProgress);
+    assert!(result.applied[2].event.forced);
+
+    let rejected: Vec<(usize, &RejectReason)> = result.rejected.iter().map(|r| (r.line, &r.reason)).collect();
+    assert_eq!(rejected, vec![
+        (2, &RejectReason::IllegalTransition { from: Status::Todo, to: Status::Done }),
+        (6, &RejectReason::NoChange),
+        (7, &RejectReason::UnknownTask("Ghost".into())),
+    ]);
+    assert_eq!(
+        result.rejected[0].to_string(),
+        "line 2: transition TODO -> DONE is not allowed (add force to override)"
+    );
+}
+
+#[test]
+fn custom_rules_and_ambiguous_titles() {
+    let mut rules = TransitionRules::from_str("TODO->IN_PROGRESS, IN_PROGRESS->DONE").unwrap();
+    let result = replay(tasks(), "move \"Fix bug\" TODO", &rules);
+    assert!(matches!(result.rejected[0].reason, RejectReason::IllegalTransition { .. }));
+
+    rules.allow(Status::Todo, Status::Done);
+    let result = replay(tasks(), "move \"Write docs\" DONE", &rules);
+    assert!(result.rejected.is_empty());
+
+    let twins = vec![Task::new("Same", Priority::Low, Status::Todo); 2];
+    let result = replay(twins, "move Same IN_PROGRESS", &rules);
+    assert_eq!(result.rejected[0].reason, RejectReason::AmbiguousTask("Same".into(), 2));
+
+    assert_eq!(
+        TransitionRules::from_str("TODO>DONE").unwrap_err(),
+        "Niepoprawne przejscie: TODO>DONE (oczekiwano STATUS->STATUS)"
+    );
+}
+
+#[test]
+fn final_state_can_be_printed_as_task_lines() {
+    let options = parse_args(["--stan", "--zapytanie", "status!=DONE"]).unwrap();
+    let result = replay(tasks(), "move \"Deploy api\" IN_PROGRESS", &options.rules);
+    let lines = render(&options, &result.tasks);
+    assert_eq!(lines, vec![
+        "Deploy api | high | IN_PROGRESS | @ania | #ops",
+        "Write docs | low | TODO",
+        "Fix bug | medium | IN_PROGRESS",
+    ]);
+    // Linie stanu da się wczytać ponownie.
+    assert_eq!(parse_tasks(&lines.join("\n")).unwrap(), result.tasks);
+}
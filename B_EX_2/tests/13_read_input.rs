@@ -0,0 +1,36 @@
+use std::io::{self, BufReader, Read};
+
+use b_ex_2::domain::TaskParseError;
+use b_ex_2::{diagnose_tasks, read_input, run_from_reader};
+
+/// Czytnik, który zawsze zgłasza błąd wejścia/wyjścia.
+struct Broken;
+
+impl Read for Broken {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("dysk odlaczony"))
+    }
+}
+
+#[test]
+fn invalid_utf8_does_not_end_the_input() {
+    let input: &[u8] = b"A | high | todo\r\nB\xff | low | done\nC | hi\xffgh | todo\n";
+    let text = read_input(input).unwrap();
+    assert_eq!(text, "A | high | todo\nB\u{fffd} | low | done\nC | hi\u{fffd}gh | todo");
+
+    // Linie po niepoprawnych bajtach nadal są czytane, a błąd wskazuje numer linii.
+    let diagnosis = diagnose_tasks(&text);
+    assert_eq!(diagnosis.tasks.len(), 2);
+    assert_eq!(diagnosis.errors[0].to_string(), "line 3: unknown priority \"hi\u{fffd}gh\"");
+}
+
+#[test]
+fn io_errors_are_reported_with_the_line_number() {
+    let reader = BufReader::new(b"A | high | todo\n".chain(Broken));
+    let err = read_input(reader).unwrap_err();
+    assert_eq!(err, TaskParseError::Unreadable("linia 2: dysk odlaczony".into()));
+    assert_eq!(err.to_string(), "Nie mozna odczytac wejscia: linia 2: dysk odlaczony");
+
+    let reader = BufReader::new(b"A | high | todo\n".chain(Broken));
+    assert!(matches!(run_from_reader(reader), Err(TaskParseError::Unreadable(_))));
+}
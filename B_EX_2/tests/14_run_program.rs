@@ -0,0 +1,68 @@
+use std::fs;
+
+use b_ex_2::{run_program, Outcome};
+
+fn program(args: &[&str], input: &str) -> Outcome {
+    run_program(args, input.as_bytes())
+}
+
+#[test]
+fn valid_input_prints_the_report_and_exits_with_zero() {
+    let outcome = program(&[], "A | high | todo\nB | low | done | @ola\n");
+    assert_eq!(outcome, Outcome {
+        stdout: vec![
+            "TODO: 1 (high: 1, medium: 0, low: 0)".to_string(),
+            "IN_PROGRESS: 0 (high: 0, medium: 0, low: 0)".to_string(),
+            "DONE: 1 (high: 0, medium: 0, low: 1)".to_string(),
+        ],
+        stderr: vec![],
+        exit_code: 0,
+    });
+}
+
+#[test]
+fn bad_lines_are_reported_next_to_the_report() {
+    let outcome = program(&["--stan"], "A | high | todo\nB | urgent | done\n");
+    assert_eq!(outcome.stdout, vec!["A | high | TODO"]);
+    assert_eq!(outcome.stderr, vec!["line 2: unknown priority \"urgent\""]);
+    assert_eq!(outcome.exit_code, 1);
+
+    // Same błędne linie: bez raportu, ale z listą błędów.
+    let outcome = program(&[], "broken\n");
+    assert!(outcome.stdout.is_empty());
+    assert_eq!(outcome.stderr, vec!["line 1: invalid line format \"broken\""]);
+    assert_eq!(outcome.exit_code, 1);
+
+    let outcome = program(&[], " \n\n");
+    assert_eq!((outcome.stderr, outcome.exit_code), (vec!["Brak zadan".to_string()], 1));
+}
+
+#[test]
+fn rejected_events_are_listed_after_the_report() {
+    let path = std::env::temp_dir().join(format!("b_ex_2_events_{}.log", std::process::id()));
+    fs::write(&path, "move A IN_PROGRESS\nmove A TODO\nmove Ghost DONE\n").unwrap();
+    let events = format!("--zdarzenia={}", path.display());
+
+    let outcome = program(&[&events, "--reguly", "TODO->IN_PROGRESS", "--stan"], "A | high | todo\n");
+    assert_eq!(outcome.stdout, vec!["A | high | IN_PROGRESS"]);
+    assert_eq!(outcome.stderr, vec![
+        "Odrzucone zdarzenia (2):",
+        "line 2: transition IN_PROGRESS -> TODO is not allowed (add force to override)",
+        "line 3: unknown task \"Ghost\"",
+    ]);
+    assert_eq!(outcome.exit_code, 1);
+
+    fs::remove_file(&path).unwrap();
+    let outcome = program(&[&events], "A | high | todo\n");
+    assert!(outcome.stdout.is_empty());
+    assert!(outcome.stderr[0].starts_with(&format!("Nie mozna odczytac {}", path.display())));
+    assert_eq!(outcome.exit_code, 2);
+}
+
+#[test]
+fn argument_errors_exit_with_two() {
+    let outcome = program(&["--format", "xml"], "A | high | todo\n");
+    assert!(outcome.stdout.is_empty());
+    assert_eq!(outcome.stderr, vec!["Nieznany format: xml (dostepne: tekst, json, csv, markdown)"]);
+    assert_eq!(outcome.exit_code, 2);
+}
@@ -0,0 +1,114 @@
+//! Alokator liczący zajętą pamięć sterty i jej szczyt, z opcjonalnym twardym limitem.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Początek komunikatu, który domyślna obsługa `handle_alloc_error` biblioteki standardowej
+/// wypisuje na stderr przed przerwaniem procesu (`memory allocation of N bytes failed`).
+pub const ALLOC_FAILURE_MESSAGE: &str = "memory allocation of";
+
+/// Nakładka na alokator systemowy. Alokacja, która przekroczyłaby limit, kończy się
+/// niepowodzeniem (wskaźnik zerowy), więc biblioteka standardowa wywołuje
+/// `handle_alloc_error`, który wypisuje [`ALLOC_FAILURE_MESSAGE`] i przerywa proces.
+/// Sprawdzarka rozpoznaje MLE po tym komunikacie; sam alokator nie kończy procesu.
+pub struct PeakAlloc {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+    /// Limit w bajtach; `0` oznacza brak limitu.
+    limit: AtomicUsize,
+    exceeded: AtomicBool,
+}
+
+impl PeakAlloc {
+    pub const fn new() -> Self {
+        Self {
+            current: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+            limit: AtomicUsize::new(0),
+            exceeded: AtomicBool::new(false),
+        }
+    }
+
+    /// Ustawia limit pamięci w bajtach (`0` wyłącza limit).
+    pub fn set_limit(&self, bytes: usize) {
+        self.limit.store(bytes, Ordering::SeqCst);
+    }
+
+    /// Największa dotąd zajęta pamięć w bajtach.
+    pub fn peak(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+
+    /// Czy któraś alokacja została odrzucona z powodu limitu.
+    pub fn limit_exceeded(&self) -> bool {
+        self.exceeded.load(Ordering::SeqCst)
+    }
+
+    /// Rezerwuje `size` bajtów w liczniku. Zwraca `false` i niczego nie rezerwuje,
+    /// jeśli rezerwacja przekroczyłaby limit.
+    fn reserve(&self, size: usize) -> bool {
+        let current = self.current.fetch_add(size, Ordering::SeqCst) + size;
+        let limit = self.limit.load(Ordering::SeqCst);
+        if limit != 0 && current > limit {
+            self.release(size);
+            self.exceeded.store(true, Ordering::SeqCst);
+            return false;
+        }
+        self.peak.fetch_max(current, Ordering::SeqCst);
+        true
+    }
+
+    fn release(&self, size: usize) {
+        self.current.fetch_sub(size, Ordering::SeqCst);
+    }
+}
+
+impl Default for PeakAlloc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if !self.reserve(layout.size()) {
+            return ptr::null_mut();
+        }
+        let ptr = System.alloc(layout);
+        if ptr.is_null() {
+            self.release(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if !self.reserve(layout.size()) {
+            return ptr::null_mut();
+        }
+        let ptr = System.alloc_zeroed(layout);
+        if ptr.is_null() {
+            self.release(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.release(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size > layout.size() && !self.reserve(new_size - layout.size()) {
+            return ptr::null_mut();
+        }
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if new_ptr.is_null() {
+            if new_size > layout.size() {
+                self.release(new_size - layout.size());
+            }
+        } else if new_size < layout.size() {
+            self.release(layout.size() - new_size);
+        }
+        new_ptr
+    }
+}
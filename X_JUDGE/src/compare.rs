@@ -0,0 +1,73 @@
+//! Porównywanie wyniku rozwiązania z oczekiwanym.
+use std::str::FromStr;
+
+/// Sposób porównania wyjścia.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Comparison {
+    /// Bajt w bajt, łącznie z białymi znakami.
+    Exact,
+    /// Ta sama sekwencja tokenów rozdzielonych białymi znakami (jak w większości olimpiad).
+    #[default]
+    Tokens,
+}
+
+impl Comparison {
+    pub fn matches(self, actual: &str, expected: &str) -> bool {
+        match self {
+            Comparison::Exact => actual == expected,
+            Comparison::Tokens => actual.split_whitespace().eq(expected.split_whitespace()),
+        }
+    }
+
+    /// Opis pierwszej różnicy (do szczegółów werdyktu WA).
+    pub fn first_difference(self, actual: &str, expected: &str) -> Option<String> {
+        match self {
+            Comparison::Exact => {
+                let position = actual
+                    .bytes()
+                    .zip(expected.bytes())
+                    .position(|(a, e)| a != e)
+                    .unwrap_or_else(|| actual.len().min(expected.len()));
+                (actual != expected).then(|| {
+                    // Liczymy bajty, bo `position` może wypaść w środku znaku UTF-8.
+                    let line = expected.as_bytes()[..position.min(expected.len())]
+                        .iter()
+                        .filter(|&&byte| byte == b'\n')
+                        .count()
+                        + 1;
+                    format!("różnica w bajcie {} (linia {line})", position + 1)
+                })
+            }
+            Comparison::Tokens => {
+                let mut actual_tokens = actual.split_whitespace();
+                let mut expected_tokens = expected.split_whitespace();
+                let mut index = 1;
+                loop {
+                    match (actual_tokens.next(), expected_tokens.next()) {
+                        (None, None) => return None,
+                        (a, e) if a == e => index += 1,
+                        (a, e) => {
+                            return Some(format!(
+                                "token {index}: otrzymano {}, oczekiwano {}",
+                                a.unwrap_or("<koniec>"),
+                                e.unwrap_or("<koniec>")
+                            ))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Comparison {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_lowercase().as_str() {
+            "dokladne" | "dokładne" | "exact" => Ok(Comparison::Exact),
+            "tokeny" | "tokens" => Ok(Comparison::Tokens),
+            _ => Err(format!("Nieznany sposób porównania: {}", raw.trim())),
+        }
+    }
+}
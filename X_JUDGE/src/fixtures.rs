@@ -0,0 +1,131 @@
+//! Wyszukiwanie testów: każdy plik `<nazwa>.in` ma obok siebie oczekiwany wynik
+//! `<nazwa>.out` (albo `<nazwa>.ans`). Katalogi są przeszukiwane rekurencyjnie.
+use std::cmp::Ordering;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Rozszerzenia plików z oczekiwanym wynikiem, w kolejności wyszukiwania.
+const EXPECTED_EXTENSIONS: [&str; 2] = ["out", "ans"];
+
+/// Para plików wejścia i oczekiwanego wyniku.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestCase {
+    /// Nazwa testu: ścieżka pliku wejścia względem przeszukiwanego katalogu, bez `.in`.
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: PathBuf,
+}
+
+/// Wynik wyszukiwania: znalezione testy i pliki `.in` bez oczekiwanego wyniku.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Discovery {
+    pub cases: Vec<TestCase>,
+    pub orphans: Vec<PathBuf>,
+}
+
+/// Przeszukuje katalogi (albo pojedyncze pliki `.in`) i zwraca testy w porządku naturalnym
+/// (`2.in` przed `10.in`).
+pub fn discover<P: AsRef<Path>>(roots: &[P]) -> Result<Discovery, String> {
+    let mut discovery = Discovery::default();
+    for root in roots {
+        let root = root.as_ref();
+        let base = if root.is_dir() {
+            root
+        } else {
+            root.parent().unwrap_or(Path::new(""))
+        };
+        let mut inputs = Vec::new();
+        collect_inputs(root, &mut inputs)?;
+
+        for input in inputs {
+            let relative = input.strip_prefix(base).unwrap_or(&input);
+            let name = relative.with_extension("").to_string_lossy().into_owned();
+            let expected = EXPECTED_EXTENSIONS
+                .iter()
+                .map(|extension| input.with_extension(extension))
+                .find(|path| path.is_file());
+            match expected {
+                Some(expected) => discovery.cases.push(TestCase {
+                    name,
+                    input,
+                    expected,
+                }),
+                None => discovery.orphans.push(input),
+            }
+        }
+    }
+
+    discovery
+        .cases
+        .sort_by(|a, b| natural_cmp(&a.name, &b.name));
+    Ok(discovery)
+}
+
+fn collect_inputs(path: &Path, inputs: &mut Vec<PathBuf>) -> Result<(), String> {
+    if path.is_file() {
+        if path.extension().is_some_and(|extension| extension == "in") {
+            inputs.push(path.to_path_buf());
+            return Ok(());
+        }
+        return Err(format!(
+            "To nie jest plik wejścia testu: {}",
+            path.display()
+        ));
+    }
+
+    let entries = fs::read_dir(path)
+        .map_err(|err| format!("Nie udało się odczytać katalogu {}: {err}", path.display()))?;
+    for entry in entries {
+        let entry = entry.map_err(|err| format!("Błąd odczytu {}: {err}", path.display()))?;
+        let entry_path = entry.path();
+        if entry_path.is_dir() {
+            collect_inputs(&entry_path, inputs)?;
+        } else if entry_path
+            .extension()
+            .is_some_and(|extension| extension == "in")
+        {
+            inputs.push(entry_path);
+        }
+    }
+    Ok(())
+}
+
+/// Porównuje nazwy tak, by ciągi cyfr były porównywane jako liczby.
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        match (a.chars().next(), b.chars().next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let (a_digits, a_rest) = split_digits(a);
+                let (b_digits, b_rest) = split_digits(b);
+                let a_trimmed = a_digits.trim_start_matches('0');
+                let b_trimmed = b_digits.trim_start_matches('0');
+                let ordering = a_trimmed
+                    .len()
+                    .cmp(&b_trimmed.len())
+                    .then_with(|| a_trimmed.cmp(b_trimmed))
+                    .then_with(|| a_digits.len().cmp(&b_digits.len()));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                (a, b) = (a_rest, b_rest);
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                (a, b) = (&a[x.len_utf8()..], &b[y.len_utf8()..]);
+            }
+        }
+    }
+}
+
+fn split_digits(text: &str) -> (&str, &str) {
+    let end = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    text.split_at(end)
+}
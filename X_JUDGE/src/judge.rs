@@ -0,0 +1,343 @@
+//! Uruchamianie testów i werdykty.
+//!
+//! Każdy test jest wykonywany w osobnym procesie: sprawdzarka uruchamia samą siebie
+//! w trybie `--wykonaj`, w którym rozwiązanie działa pod kontrolą [`PeakAlloc`].
+//! Proces rodzica mierzy czas ścienny i przerywa proces po przekroczeniu limitu czasu,
+//! a proces potomny zgłasza szczyt zajętej pamięci w ostatniej linii stderr. Przekroczenie
+//! limitu pamięci przerywa proces potomny, a rodzic rozpoznaje je po komunikacie na stderr.
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::alloc::{PeakAlloc, ALLOC_FAILURE_MESSAGE};
+use crate::compare::Comparison;
+use crate::fixtures::TestCase;
+use crate::solvers::Solver;
+
+/// Flaga trybu potomnego, w którym proces uruchamia pojedyncze rozwiązanie.
+pub const CHILD_FLAG: &str = "--wykonaj";
+
+/// Początek linii stderr, w której proces potomny zgłasza szczyt pamięci w bajtach.
+const PEAK_MARKER: &str = "@@szczyt-pamieci";
+
+/// Co ile proces rodzica sprawdza, czy proces potomny już się zakończył.
+const POLL_INTERVAL: Duration = Duration::from_millis(2);
+
+/// Limity wykonania pojedynczego testu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Limit czasu ściennego.
+    pub time: Duration,
+    /// Limit pamięci sterty w bajtach.
+    pub memory: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            time: Duration::from_secs(1),
+            memory: 256 * 1024 * 1024,
+        }
+    }
+}
+
+/// Werdykt pojedynczego testu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Verdict {
+    Ok,
+    WrongAnswer,
+    TimeLimitExceeded,
+    MemoryLimitExceeded,
+    RuntimeError,
+}
+
+impl Verdict {
+    pub const ALL: [Verdict; 5] = [
+        Verdict::Ok,
+        Verdict::WrongAnswer,
+        Verdict::TimeLimitExceeded,
+        Verdict::MemoryLimitExceeded,
+        Verdict::RuntimeError,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Verdict::Ok => "OK",
+            Verdict::WrongAnswer => "WA",
+            Verdict::TimeLimitExceeded => "TLE",
+            Verdict::MemoryLimitExceeded => "MLE",
+            Verdict::RuntimeError => "RE",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Wynik pojedynczego testu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub name: String,
+    pub verdict: Verdict,
+    pub time: Duration,
+    /// Szczyt pamięci sterty w bajtach; `None`, jeśli proces go nie zgłosił.
+    pub memory: Option<usize>,
+    /// Szczegóły werdyktu innego niż OK.
+    pub detail: Option<String>,
+}
+
+/// Przebieg procesu potomnego, na podstawie którego zapada werdykt.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Execution {
+    /// Kod wyjścia; `None`, jeśli proces zakończył sygnał (np. przerwanie po przekroczeniu
+    /// limitu pamięci albo czasu).
+    pub exit_code: Option<i32>,
+    /// Czy proces przerwano po przekroczeniu limitu czasu.
+    pub timed_out: bool,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Tryb potomny: wczytuje całe stdin, uruchamia rozwiązanie z limitem pamięci
+/// i wypisuje jego wynik. Zwraca kod wyjścia procesu.
+pub fn run_child(solver: &Solver, allocator: &PeakAlloc, memory_limit: usize) -> i32 {
+    allocator.set_limit(memory_limit);
+
+    let mut input = String::new();
+    if let Err(err) = io::stdin().read_to_string(&mut input) {
+        eprintln!("Nie udało się odczytać wejścia: {err}");
+        return 1;
+    }
+    let output = match (solver.solve)(&input) {
+        Ok(output) => output,
+        Err(message) => {
+            eprintln!("{message}");
+            return 1;
+        }
+    };
+
+    let mut stdout = io::stdout().lock();
+    if let Err(err) = stdout
+        .write_all(output.as_bytes())
+        .and_then(|_| stdout.flush())
+    {
+        eprintln!("Nie udało się zapisać wyniku: {err}");
+        return 1;
+    }
+    eprintln!("{PEAK_MARKER} {}", allocator.peak());
+    0
+}
+
+/// Uruchamia test w procesie potomnym `executable` (zwykle bieżący program) i ocenia wynik.
+pub fn run_case(
+    executable: &Path,
+    solver: &Solver,
+    case: &TestCase,
+    limits: &Limits,
+    comparison: Comparison,
+) -> Result<Outcome, String> {
+    let read = |path: &Path| {
+        fs::read(path).map_err(|err| format!("Nie udało się odczytać {}: {err}", path.display()))
+    };
+    let input = read(&case.input)?;
+    let expected = String::from_utf8_lossy(&read(&case.expected)?).into_owned();
+
+    let mut child = Command::new(executable)
+        .arg(CHILD_FLAG)
+        .arg(solver.name)
+        .arg(limits.memory.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("Nie udało się uruchomić {}: {err}", executable.display()))?;
+
+    let start = Instant::now();
+    // Wejście i wyjścia obsługują osobne wątki, żeby duże dane nie zablokowały potoków.
+    let mut stdin = child.stdin.take();
+    let writer = thread::spawn(move || {
+        if let Some(stdin) = stdin.as_mut() {
+            // Rozwiązanie może zakończyć się przed przeczytaniem całego wejścia.
+            let _ = stdin.write_all(&input);
+        }
+    });
+    let stdout = spawn_reader(child.stdout.take());
+    let stderr = spawn_reader(child.stderr.take());
+
+    let (status, timed_out) = loop {
+        let finished = child
+            .try_wait()
+            .map_err(|err| format!("Błąd oczekiwania na proces: {err}"))?;
+        if let Some(status) = finished {
+            break (status, false);
+        }
+        if start.elapsed() > limits.time {
+            let _ = child.kill();
+            let status = child
+                .wait()
+                .map_err(|err| format!("Błąd oczekiwania na proces: {err}"))?;
+            break (status, true);
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+    let time = start.elapsed();
+
+    let _ = writer.join();
+    let execution = Execution {
+        exit_code: status.code(),
+        timed_out,
+        stdout: String::from_utf8_lossy(&stdout.join().unwrap_or_default()).into_owned(),
+        stderr: String::from_utf8_lossy(&stderr.join().unwrap_or_default()).into_owned(),
+    };
+    let memory = execution
+        .stderr
+        .lines()
+        .find_map(|line| line.strip_prefix(PEAK_MARKER))
+        .and_then(|peak| peak.trim().parse().ok());
+    let (verdict, detail) = classify(&execution, &expected, limits, comparison);
+
+    Ok(Outcome {
+        name: case.name.clone(),
+        verdict,
+        time,
+        memory,
+        detail,
+    })
+}
+
+fn spawn_reader<R: Read + Send + 'static>(source: Option<R>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut source) = source {
+            let _ = source.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
+/// Werdykt i jego szczegóły dla zakończonego procesu potomnego.
+pub fn classify(
+    execution: &Execution,
+    expected: &str,
+    limits: &Limits,
+    comparison: Comparison,
+) -> (Verdict, Option<String>) {
+    let errors = &execution.stderr;
+    if execution.timed_out {
+        (
+            Verdict::TimeLimitExceeded,
+            Some(format!("przerwano po {} ms", limits.time.as_millis())),
+        )
+    } else if execution.exit_code != Some(0) && errors.contains(ALLOC_FAILURE_MESSAGE) {
+        (
+            Verdict::MemoryLimitExceeded,
+            Some(format!("limit {}", format_memory(limits.memory))),
+        )
+    } else if execution.exit_code != Some(0) {
+        let detail = runtime_detail(execution.exit_code, errors);
+        (Verdict::RuntimeError, Some(detail))
+    } else if comparison.matches(&execution.stdout, expected) {
+        (Verdict::Ok, None)
+    } else {
+        let detail = comparison.first_difference(&execution.stdout, expected);
+        (Verdict::WrongAnswer, detail)
+    }
+}
+
+/// Opis błędu wykonania: komunikat paniki, pierwsza linia stderr albo kod wyjścia.
+fn runtime_detail(exit_code: Option<i32>, errors: &str) -> String {
+    let lines: Vec<&str> = errors
+        .lines()
+        .filter(|line| !line.starts_with(PEAK_MARKER) && !line.trim().is_empty())
+        .collect();
+    let message = match lines.iter().position(|line| line.contains("panicked at")) {
+        Some(index) => lines.get(index + 1).or(lines.get(index)).copied(),
+        None => lines.first().copied(),
+    };
+    match (message, exit_code) {
+        (Some(message), _) => message.trim().to_string(),
+        (None, Some(code)) => format!("kod wyjścia {code}"),
+        (None, None) => "proces przerwany sygnałem".to_string(),
+    }
+}
+
+/// Formatuje liczbę bajtów w KB albo MB (potęgi 1024) z jednym miejscem po przecinku.
+pub fn format_memory(bytes: usize) -> String {
+    const KB: f64 = 1024.0;
+    let bytes = bytes as f64;
+    if bytes < KB * KB {
+        format!("{:.1} KB", bytes / KB)
+    } else {
+        format!("{:.1} MB", bytes / (KB * KB))
+    }
+}
+
+/// Tabela werdyktów, szczegóły nieudanych testów i podsumowanie.
+pub fn render_table(outcomes: &[Outcome]) -> Vec<String> {
+    let name_width = outcomes
+        .iter()
+        .map(|outcome| outcome.name.chars().count())
+        .chain(["Test".len()])
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = vec![format!(
+        "{:<name_width$}  {:<5}  {:>9}  {:>9}",
+        "Test", "Wynik", "Czas", "Pamięć"
+    )];
+    for outcome in outcomes {
+        let memory = outcome
+            .memory
+            .map_or_else(|| "-".to_string(), format_memory);
+        lines.push(format!(
+            "{:<name_width$}  {:<5}  {:>9}  {:>9}",
+            outcome.name,
+            outcome.verdict.label(),
+            format!("{} ms", outcome.time.as_millis()),
+            memory
+        ));
+    }
+
+    let failed: Vec<&Outcome> = outcomes
+        .iter()
+        .filter(|outcome| outcome.detail.is_some())
+        .collect();
+    if !failed.is_empty() {
+        lines.push(String::new());
+        for outcome in failed {
+            lines.push(format!(
+                "{} ({}): {}",
+                outcome.name,
+                outcome.verdict.label(),
+                outcome.detail.as_deref().unwrap_or_default()
+            ));
+        }
+    }
+
+    lines.push(String::new());
+    lines.push(summary_line(outcomes));
+    lines
+}
+
+/// Np. `Wynik: 3/5 OK (WA: 1, TLE: 1)`.
+pub fn summary_line(outcomes: &[Outcome]) -> String {
+    let count = |verdict: Verdict| outcomes.iter().filter(|o| o.verdict == verdict).count();
+    let others: Vec<String> = Verdict::ALL
+        .iter()
+        .skip(1)
+        .filter(|&&verdict| count(verdict) > 0)
+        .map(|&verdict| format!("{verdict}: {}", count(verdict)))
+        .collect();
+    let mut line = format!("Wynik: {}/{} OK", count(Verdict::Ok), outcomes.len());
+    if !others.is_empty() {
+        line.push_str(&format!(" ({})", others.join(", ")));
+    }
+    line
+}
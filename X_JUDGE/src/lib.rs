@@ -0,0 +1,7 @@
+//! Lokalna sprawdzarka dla rozwiązań z poziomu X: wyszukuje pary testów (`.in`/`.out`),
+//! uruchamia rozwiązanie z limitem czasu i pamięci, porównuje wyniki i wypisuje werdykty.
+pub mod alloc;
+pub mod compare;
+pub mod fixtures;
+pub mod judge;
+pub mod solvers;
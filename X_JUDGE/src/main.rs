@@ -0,0 +1,177 @@
+use std::env;
+use std::process;
+use std::time::Duration;
+
+use x_judge::alloc::PeakAlloc;
+use x_judge::compare::Comparison;
+use x_judge::fixtures;
+use x_judge::judge::{self, Limits, Verdict};
+use x_judge::solvers;
+
+#[global_allocator]
+static ALLOCATOR: PeakAlloc = PeakAlloc::new();
+
+/// Ustawienia odczytane z argumentów wiersza poleceń.
+#[derive(Debug, Clone, Default)]
+struct Options {
+    solver: Option<String>,
+    paths: Vec<String>,
+    limits: Limits,
+    comparison: Comparison,
+    list: bool,
+}
+
+fn usage() -> Vec<String> {
+    let mut lines = vec![
+        "Użycie: x_judge <rozwiązanie> <katalog|plik.in>... [--czas <ms>] [--pamiec <MB>] [--porownanie tokeny|dokladne]".to_string(),
+        String::new(),
+        "Rozwiązania:".to_string(),
+    ];
+    lines.extend(
+        solvers::SOLVERS
+            .iter()
+            .map(|solver| format!("  {}  {}", solver.name, solver.description)),
+    );
+    lines
+}
+
+/// Zwraca wartość flagi: podaną po `=` albo kolejny argument.
+fn take_value<I>(flag: &str, inline: Option<&str>, rest: &mut I) -> Result<String, String>
+where
+    I: Iterator<Item = String>,
+{
+    match inline {
+        Some(value) => Ok(value.to_string()),
+        None => rest
+            .next()
+            .ok_or_else(|| format!("Brak wartości dla {flag}")),
+    }
+}
+
+fn parse_positive(flag: &str, value: &str) -> Result<u64, String> {
+    match value.trim().parse::<u64>() {
+        Ok(number) if number > 0 => Ok(number),
+        _ => Err(format!("Niepoprawna wartość dla {flag}: {value}")),
+    }
+}
+
+fn parse_args<I>(args: I) -> Result<Options, String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut options = Options::default();
+    let mut args = args.into_iter().map(|arg| arg.as_ref().to_string());
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value)),
+            _ => (arg.as_str(), None),
+        };
+
+        match flag {
+            "--czas" => {
+                let millis = parse_positive(flag, &take_value(flag, inline, &mut args)?)?;
+                options.limits.time = Duration::from_millis(millis);
+            }
+            "--pamiec" => {
+                let megabytes = parse_positive(flag, &take_value(flag, inline, &mut args)?)?;
+                options.limits.memory = usize::try_from(megabytes)
+                    .ok()
+                    .and_then(|mb| mb.checked_mul(1024 * 1024))
+                    .ok_or_else(|| format!("Za duży limit pamięci: {megabytes}"))?;
+            }
+            "--porownanie" => options.comparison = take_value(flag, inline, &mut args)?.parse()?,
+            "--lista" if inline.is_none() => options.list = true,
+            _ if flag.starts_with("--") => return Err(format!("Nieznany argument: {arg}")),
+            _ if options.solver.is_none() => options.solver = Some(arg),
+            _ => options.paths.push(arg),
+        }
+    }
+    Ok(options)
+}
+
+/// Tryb potomny: `x_judge --wykonaj <rozwiązanie> <limit pamięci w bajtach>`.
+fn run_child(args: &[String]) -> i32 {
+    let (Some(name), Some(limit)) = (args.first(), args.get(1)) else {
+        eprintln!(
+            "Użycie: x_judge {} <rozwiązanie> <bajty>",
+            judge::CHILD_FLAG
+        );
+        return 2;
+    };
+    let (Some(solver), Ok(limit)) = (solvers::find(name), limit.parse::<usize>()) else {
+        eprintln!("Niepoprawne argumenty trybu {}", judge::CHILD_FLAG);
+        return 2;
+    };
+    judge::run_child(solver, &ALLOCATOR, limit)
+}
+
+fn run(options: &Options) -> Result<bool, String> {
+    let name = options
+        .solver
+        .as_deref()
+        .ok_or_else(|| "Nie podano rozwiązania.".to_string())?;
+    let solver = solvers::find(name).ok_or_else(|| format!("Nieznane rozwiązanie: {name}"))?;
+    if options.paths.is_empty() {
+        return Err("Nie podano katalogu z testami.".to_string());
+    }
+
+    let discovery = fixtures::discover(&options.paths)?;
+    for orphan in &discovery.orphans {
+        eprintln!("Pominięto {} (brak pliku .out)", orphan.display());
+    }
+    if discovery.cases.is_empty() {
+        return Err("Nie znaleziono testów.".to_string());
+    }
+
+    let executable = env::current_exe()
+        .map_err(|err| format!("Nie udało się ustalić ścieżki programu: {err}"))?;
+    let mut outcomes = Vec::new();
+    for case in &discovery.cases {
+        outcomes.push(judge::run_case(
+            &executable,
+            solver,
+            case,
+            &options.limits,
+            options.comparison,
+        )?);
+    }
+
+    for line in judge::render_table(&outcomes) {
+        println!("{line}");
+    }
+    Ok(outcomes
+        .iter()
+        .all(|outcome| outcome.verdict == Verdict::Ok))
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some(judge::CHILD_FLAG) {
+        process::exit(run_child(&args[1..]));
+    }
+
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            process::exit(2);
+        }
+    };
+    if options.list || options.solver.is_none() {
+        for line in usage() {
+            println!("{line}");
+        }
+        return;
+    }
+
+    match run(&options) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(message) => {
+            eprintln!("{message}");
+            process::exit(2);
+        }
+    }
+}
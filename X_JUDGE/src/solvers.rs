@@ -0,0 +1,79 @@
+//! Rozwiązania, które sprawdzarka potrafi uruchomić, wraz z ich punktami wejścia.
+use std::io::Write;
+
+/// Uruchamia rozwiązanie na całym wejściu i zwraca to, co wypisałby jego program.
+pub type SolveFn = fn(&str) -> Result<String, String>;
+
+/// Rozwiązanie dostępne w sprawdzarce.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub solve: SolveFn,
+}
+
+/// Wszystkie rozwiązania z poziomu X.
+pub const SOLVERS: &[Solver] = &[
+    Solver {
+        name: "x_ex_2",
+        description: "Segmentowe minimum z leniwym dodawaniem",
+        solve: solve_x_ex_2,
+    },
+    Solver {
+        name: "x_ex_3",
+        description: "Logarytmiczne zapytania na drzewie",
+        solve: solve_x_ex_3,
+    },
+    Solver {
+        name: "x_ex_4",
+        description: "Maksymalny przepływ i minimalny przekrój",
+        solve: solve_x_ex_4,
+    },
+    Solver {
+        name: "x_ex_5",
+        description: "FFT-owa korelacja sekwencji",
+        solve: solve_x_ex_5,
+    },
+];
+
+/// Szuka rozwiązania po nazwie (bez względu na wielkość liter).
+pub fn find(name: &str) -> Option<&'static Solver> {
+    SOLVERS
+        .iter()
+        .find(|solver| solver.name.eq_ignore_ascii_case(name))
+}
+
+/// Składa wyjście tak jak `main` rozwiązania: wyniki albo komunikat błędu w osobnej linii.
+fn render<T, F>(result: Result<T, String>, write: F) -> Result<String, String>
+where
+    F: FnOnce(&T, &mut Vec<u8>) -> std::io::Result<()>,
+{
+    let mut output = Vec::new();
+    match result {
+        Ok(answers) => write(&answers, &mut output).map_err(|err| err.to_string())?,
+        Err(message) => writeln!(output, "{message}").map_err(|err| err.to_string())?,
+    }
+    String::from_utf8(output).map_err(|_| "Wynik nie jest poprawnym UTF-8.".to_string())
+}
+
+fn solve_x_ex_2(input: &str) -> Result<String, String> {
+    render(x_ex_2::solve_from_str(input), |answers, output| {
+        x_ex_2::write_answers(answers, output)
+    })
+}
+
+fn solve_x_ex_3(input: &str) -> Result<String, String> {
+    render(x_ex_3::solve_from_str(input), |answers, output| {
+        x_ex_3::write_answers(answers, output)
+    })
+}
+
+fn solve_x_ex_4(input: &str) -> Result<String, String> {
+    render(x_ex_4::solve_from_str(input), x_ex_4::write_answer)
+}
+
+fn solve_x_ex_5(input: &str) -> Result<String, String> {
+    render(x_ex_5::solve_from_str(input), |answers, output| {
+        x_ex_5::write_answers(answers, output)
+    })
+}
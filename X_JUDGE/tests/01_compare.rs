@@ -0,0 +1,39 @@
+use x_judge::compare::Comparison;
+
+#[test]
+fn exact_comparison_reports_byte_and_line() {
+    let exact = Comparison::Exact;
+    assert!(exact.matches("1\n2\n", "1\n2\n"));
+    assert!(!exact.matches("1\n2", "1\n2\n"));
+    assert_eq!(exact.first_difference("1\n2\n", "1\n2\n"), None);
+    assert_eq!(exact.first_difference("1\n3\n", "1\n2\n"), Some("różnica w bajcie 3 (linia 2)".to_string()));
+    assert_eq!(exact.first_difference("1\n2", "1\n2\n"), Some("różnica w bajcie 4 (linia 2)".to_string()));
+}
+
+#[test]
+fn exact_comparison_handles_multibyte_characters() {
+    let exact = Comparison::Exact;
+    // „ą” (0xC4 0x85) i „ę” (0xC4 0x99) różnią się dopiero w drugim bajcie znaku.
+    assert_eq!(exact.first_difference("a\ną", "a\nę"), Some("różnica w bajcie 4 (linia 2)".to_string()));
+    assert_eq!(exact.first_difference("żółw\n", "żółć\n"), Some("różnica w bajcie 7 (linia 1)".to_string()));
+    assert_eq!(exact.first_difference("ą\n", "ą"), Some("różnica w bajcie 3 (linia 1)".to_string()));
+}
+
+#[test]
+fn token_comparison_ignores_whitespace() {
+    let tokens = Comparison::Tokens;
+    assert!(tokens.matches("1  2\r\n3", "1 2 3\n"));
+    assert!(!tokens.matches("1 2", "1 2 3"));
+    assert_eq!(tokens.first_difference("1 2", "1 2"), None);
+    assert_eq!(tokens.first_difference("1 5 3", "1 2 3"), Some("token 2: otrzymano 5, oczekiwano 2".to_string()));
+    assert_eq!(tokens.first_difference("zażółć", "zażółć gęślą"), Some("token 2: otrzymano <koniec>, oczekiwano gęślą".to_string()));
+    assert_eq!(tokens.first_difference("1 2", "1"), Some("token 2: otrzymano 2, oczekiwano <koniec>".to_string()));
+}
+
+#[test]
+fn comparison_names() {
+    assert_eq!("dokładne".parse(), Ok(Comparison::Exact));
+    assert_eq!(" TOKENS ".parse(), Ok(Comparison::Tokens));
+    assert_eq!(Comparison::default(), Comparison::Tokens);
+    assert_eq!("linie".parse::<Comparison>(), Err("Nieznany sposób porównania: linie".to_string()));
+}
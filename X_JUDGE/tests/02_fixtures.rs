@@ -0,0 +1,48 @@
+use std::cmp::Ordering;
+use std::fs;
+use std::path::PathBuf;
+
+use x_judge::fixtures::{discover, natural_cmp};
+
+#[test]
+fn natural_order_compares_numbers_by_value() {
+    assert_eq!(natural_cmp("2", "10"), Ordering::Less);
+    assert_eq!(natural_cmp("test10b", "test10a"), Ordering::Greater);
+    assert_eq!(natural_cmp("a/9", "a/09"), Ordering::Less);
+    assert_eq!(natural_cmp("x01", "x01"), Ordering::Equal);
+    assert_eq!(natural_cmp("ą2", "ą10"), Ordering::Less);
+    assert_eq!(natural_cmp("abc", "ab"), Ordering::Greater);
+
+    let mut names = vec!["10", "1b", "2", "1a", "01"];
+    names.sort_by(|a, b| natural_cmp(a, b));
+    assert_eq!(names, vec!["1a", "1b", "01", "2", "10"]);
+}
+
+#[test]
+fn discovery_pairs_inputs_with_outputs_recursively() {
+    let root = std::env::temp_dir().join(format!("x_judge_fixtures_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("grupa")).unwrap();
+    for file in ["10.in", "10.out", "2.in", "2.ans", "3.in", "notatki.txt", "grupa/1.in", "grupa/1.out"] {
+        fs::write(root.join(file), "").unwrap();
+    }
+
+    let discovery = discover(std::slice::from_ref(&root)).unwrap();
+    let cases: Vec<(&str, PathBuf)> = discovery
+        .cases
+        .iter()
+        .map(|case| (case.name.as_str(), case.expected.strip_prefix(&root).unwrap().to_path_buf()))
+        .collect();
+    assert_eq!(cases, vec![
+        ("2", PathBuf::from("2.ans")),
+        ("10", PathBuf::from("10.out")),
+        ("grupa/1", PathBuf::from("grupa/1.out")),
+    ]);
+    assert_eq!(discovery.orphans, vec![root.join("3.in")]);
+
+    let single = discover(&[root.join("grupa/1.in")]).unwrap();
+    assert_eq!(single.cases[0].name, "1");
+    let err = discover(&[root.join("notatki.txt")]).unwrap_err();
+    assert!(err.starts_with("To nie jest plik wejścia testu:"));
+    fs::remove_dir_all(&root).unwrap();
+}
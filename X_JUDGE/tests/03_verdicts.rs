@@ -0,0 +1,101 @@
+use std::alloc::{GlobalAlloc, Layout};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use x_judge::alloc::PeakAlloc;
+use x_judge::compare::Comparison;
+use x_judge::fixtures::TestCase;
+use x_judge::judge::{classify, run_case, summary_line, Execution, Limits, Outcome, Verdict};
+use x_judge::solvers;
+
+fn finished(stdout: &str) -> Execution {
+    Execution { exit_code: Some(0), stdout: stdout.to_string(), ..Execution::default() }
+}
+
+fn verdict(execution: &Execution) -> (Verdict, Option<String>) {
+    classify(execution, "1 2\n", &Limits::default(), Comparison::Tokens)
+}
+
+#[test]
+fn verdicts_from_finished_processes() {
+    assert_eq!(verdict(&finished("1\n2")), (Verdict::Ok, None));
+    assert_eq!(
+        verdict(&finished("1 3\n")),
+        (Verdict::WrongAnswer, Some("token 2: otrzymano 3, oczekiwano 2".to_string()))
+    );
+
+    let panicked = Execution {
+        exit_code: Some(101),
+        stderr: "thread 'main' panicked at src/main.rs:5:5:\nnot yet implemented\n".to_string(),
+        ..Execution::default()
+    };
+    assert_eq!(verdict(&panicked), (Verdict::RuntimeError, Some("not yet implemented".to_string())));
+    let silent = Execution { exit_code: Some(7), ..Execution::default() };
+    assert_eq!(verdict(&silent), (Verdict::RuntimeError, Some("kod wyjścia 7".to_string())));
+    let killed = Execution { exit_code: None, ..Execution::default() };
+    assert_eq!(verdict(&killed).1, Some("proces przerwany sygnałem".to_string()));
+}
+
+#[test]
+fn limits_take_precedence_over_the_output() {
+    let out_of_memory = Execution {
+        exit_code: None,
+        stdout: "1 2\n".to_string(),
+        stderr: "memory allocation of 4096 bytes failed\n".to_string(),
+        ..Execution::default()
+    };
+    assert_eq!(verdict(&out_of_memory), (Verdict::MemoryLimitExceeded, Some("limit 256.0 MB".to_string())));
+    let timed_out = Execution { timed_out: true, ..finished("1 2\n") };
+    assert_eq!(verdict(&timed_out), (Verdict::TimeLimitExceeded, Some("przerwano po 1000 ms".to_string())));
+}
+
+#[test]
+fn allocator_refuses_allocations_over_the_limit() {
+    let allocator = PeakAlloc::new();
+    allocator.set_limit(1024);
+    let small = Layout::from_size_align(512, 8).unwrap();
+    let large = Layout::from_size_align(2048, 8).unwrap();
+    unsafe {
+        let ptr = allocator.alloc(small);
+        assert!(!ptr.is_null());
+        assert!(allocator.alloc(large).is_null());
+        assert!(allocator.limit_exceeded());
+        assert!(allocator.realloc(ptr, small, 2048).is_null());
+        let grown = allocator.realloc(ptr, small, 1024);
+        assert!(!grown.is_null());
+        allocator.dealloc(grown, Layout::from_size_align(1024, 8).unwrap());
+    }
+    assert_eq!(allocator.peak(), 1024);
+}
+
+fn fixture(dir: &Path, input: &str, expected: &str) -> TestCase {
+    fs::create_dir_all(dir).unwrap();
+    fs::write(dir.join("1.in"), input).unwrap();
+    fs::write(dir.join("1.out"), expected).unwrap();
+    TestCase { name: "1".to_string(), input: dir.join("1.in"), expected: dir.join("1.out") }
+}
+
+#[test]
+fn child_processes_report_runtime_errors_and_memory_limits() {
+    let dir = std::env::temp_dir().join(format!("x_judge_verdicts_{}", std::process::id()));
+    let case = fixture(&dir, "3 1\n1 2 3\n2 0 3\n", "1\n");
+    let executable = Path::new(env!("CARGO_BIN_EXE_x_judge"));
+    let solver = solvers::find("x_ex_2").unwrap();
+    let limits = Limits { time: Duration::from_secs(10), ..Limits::default() };
+
+    // Rozwiązanie z nieuzupełnionym `todo!()` kończy się paniką.
+    let outcome = run_case(executable, solver, &case, &limits, Comparison::Tokens).unwrap();
+    assert_eq!(outcome.verdict, Verdict::RuntimeError);
+
+    // Wczytanie wejścia przekracza limit 1 KB, więc alokacja się nie udaje.
+    fs::write(&case.input, "7 ".repeat(4096)).unwrap();
+    let tiny = Limits { memory: 1024, ..limits };
+    let outcome = run_case(executable, solver, &case, &tiny, Comparison::Tokens).unwrap();
+    assert_eq!(outcome.verdict, Verdict::MemoryLimitExceeded);
+    assert_eq!(outcome.detail.as_deref(), Some("limit 1.0 KB"));
+    fs::remove_dir_all(&dir).unwrap();
+
+    let outcomes = [outcome.clone(), Outcome { verdict: Verdict::Ok, detail: None, ..outcome }];
+    assert_eq!(summary_line(&outcomes), "Wynik: 1/2 OK (MLE: 1)");
+}
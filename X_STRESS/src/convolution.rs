@@ -0,0 +1,130 @@
+//! `X_EX_5`: splot dwóch sekwencji i zapytania o jego wartości. Wzorzec liczy splot
+//! wprost, w czasie `O(n·m)`.
+use crate::rng::Rng;
+use crate::stress::{shrink_toward, Config, Problem, OVERFLOW};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvolutionCase {
+    pub a: Vec<i64>,
+    pub b: Vec<i64>,
+    /// Indeksy `t` z zapytań, `0 <= t < n + m - 1`.
+    pub queries: Vec<usize>,
+}
+
+impl ConvolutionCase {
+    fn result_len(&self) -> usize {
+        self.a.len() + self.b.len() - 1
+    }
+}
+
+/// Splot liczony z definicji. Zwraca `None`, gdy któraś suma nie mieści się w `i64`.
+pub fn naive_convolution(a: &[i64], b: &[i64]) -> Option<Vec<i64>> {
+    let mut result = vec![0i64; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            result[i + j] = result[i + j].checked_add(x.checked_mul(y)?)?;
+        }
+    }
+    Some(result)
+}
+
+pub struct Convolution;
+
+impl Problem for Convolution {
+    type Case = ConvolutionCase;
+
+    fn generate(&self, rng: &mut Rng, config: &Config) -> ConvolutionCase {
+        let size = config.max_size.max(1);
+        let sequence = |rng: &mut Rng| -> Vec<i64> {
+            let len = rng.range_usize(1, size);
+            (0..len)
+                .map(|_| rng.range_i64(-config.max_value, config.max_value))
+                .collect()
+        };
+        let a = sequence(rng);
+        let b = sequence(rng);
+        let len = a.len() + b.len() - 1;
+        let q = rng.range_usize(1, size);
+        let queries = (0..q).map(|_| rng.range_usize(0, len - 1)).collect();
+        ConvolutionCase { a, b, queries }
+    }
+
+    fn input(&self, case: &ConvolutionCase) -> String {
+        let join = |values: &[i64]| {
+            values
+                .iter()
+                .map(i64::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let mut text = format!(
+            "{} {} {}\n{}\n{}\n",
+            case.a.len(),
+            case.b.len(),
+            case.queries.len(),
+            join(&case.a),
+            join(&case.b)
+        );
+        for t in &case.queries {
+            text.push_str(&format!("{t}\n"));
+        }
+        text
+    }
+
+    fn expected(&self, case: &ConvolutionCase) -> String {
+        let result = naive_convolution(&case.a, &case.b).expect(OVERFLOW);
+        case.queries
+            .iter()
+            .map(|&t| format!("{}\n", result[t]))
+            .collect()
+    }
+
+    fn shrink(&self, case: &ConvolutionCase) -> Vec<ConvolutionCase> {
+        let mut candidates = Vec::new();
+
+        if case.queries.len() > 1 {
+            for index in 0..case.queries.len() {
+                let mut smaller = case.clone();
+                smaller.queries.remove(index);
+                candidates.push(smaller);
+            }
+        }
+
+        // Usunięcie elementu z A lub B; zapytania poza krótszym splotem znikają.
+        for from_a in [true, false] {
+            let sequence = if from_a { &case.a } else { &case.b };
+            if sequence.len() <= 1 {
+                continue;
+            }
+            for index in 0..sequence.len() {
+                let mut smaller = case.clone();
+                if from_a {
+                    smaller.a.remove(index);
+                } else {
+                    smaller.b.remove(index);
+                }
+                let len = smaller.result_len();
+                smaller.queries.retain(|&t| t < len);
+                if !smaller.queries.is_empty() {
+                    candidates.push(smaller);
+                }
+            }
+        }
+
+        for from_a in [true, false] {
+            let sequence = if from_a { &case.a } else { &case.b };
+            for (index, &value) in sequence.iter().enumerate() {
+                for smaller in shrink_toward(value, 0) {
+                    let mut candidate = case.clone();
+                    if from_a {
+                        candidate.a[index] = smaller;
+                    } else {
+                        candidate.b[index] = smaller;
+                    }
+                    candidates.push(candidate);
+                }
+            }
+        }
+        candidates
+    }
+}
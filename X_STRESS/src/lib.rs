@@ -0,0 +1,9 @@
+//! Testy losowe (stress testy) dla rozwiązań z poziomu X: generatory wejść z ziarnem,
+//! rozwiązania wzorcowe działające w prosty, wolny sposób, pętla porównująca wyniki
+//! i zmniejszanie pierwszego niezgodnego przypadku do minimalnego.
+pub mod convolution;
+pub mod max_flow;
+pub mod range_min;
+pub mod rng;
+pub mod stress;
+pub mod tree_path;
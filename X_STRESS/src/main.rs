@@ -0,0 +1,188 @@
+use std::env;
+use std::panic;
+use std::process;
+
+use x_judge::solvers;
+use x_stress::convolution::Convolution;
+use x_stress::max_flow::MaxFlow;
+use x_stress::range_min::RangeMin;
+use x_stress::stress::{self, Config, Problem, StressError};
+use x_stress::tree_path::TreePath;
+
+/// Zadania, dla których istnieje generator i rozwiązanie wzorcowe.
+const PROBLEMS: &[(&str, &str)] = &[
+    ("x_ex_2", "wzorzec O(n) na operację"),
+    ("x_ex_3", "wzorzec: ścieżka wyznaczana przez BFS"),
+    ("x_ex_4", "wzorzec: algorytm Edmondsa–Karpa"),
+    ("x_ex_5", "wzorzec: splot w czasie O(n·m)"),
+];
+
+/// Ustawienia odczytane z argumentów wiersza poleceń.
+#[derive(Debug, Clone, Default)]
+struct Options {
+    problem: Option<String>,
+    config: Config,
+}
+
+fn usage() -> Vec<String> {
+    let mut lines = vec![
+        "Użycie: x_stress <zadanie> [--ziarno <N>] [--iteracje <N>] [--rozmiar <N>] [--wartosci <N>]".to_string(),
+        String::new(),
+        "Zadania:".to_string(),
+    ];
+    lines.extend(
+        PROBLEMS
+            .iter()
+            .map(|(name, description)| format!("  {name}  {description}")),
+    );
+    lines
+}
+
+/// Zwraca wartość flagi: podaną po `=` albo kolejny argument.
+fn take_value<I>(flag: &str, inline: Option<&str>, rest: &mut I) -> Result<String, String>
+where
+    I: Iterator<Item = String>,
+{
+    match inline {
+        Some(value) => Ok(value.to_string()),
+        None => rest
+            .next()
+            .ok_or_else(|| format!("Brak wartości dla {flag}")),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("Niepoprawna wartość dla {flag}: {value}"))
+}
+
+fn parse_positive(flag: &str, value: &str) -> Result<usize, String> {
+    match parse_number::<usize>(flag, value)? {
+        0 => Err(format!("Niepoprawna wartość dla {flag}: {value}")),
+        number => Ok(number),
+    }
+}
+
+fn parse_args<I>(args: I) -> Result<Options, String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut options = Options::default();
+    let mut args = args.into_iter().map(|arg| arg.as_ref().to_string());
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value)),
+            _ => (arg.as_str(), None),
+        };
+
+        match flag {
+            "--ziarno" => {
+                options.config.seed = parse_number(flag, &take_value(flag, inline, &mut args)?)?
+            }
+            "--iteracje" => {
+                options.config.iterations =
+                    parse_positive(flag, &take_value(flag, inline, &mut args)?)?
+            }
+            "--rozmiar" => {
+                options.config.max_size =
+                    parse_positive(flag, &take_value(flag, inline, &mut args)?)?
+            }
+            "--wartosci" => {
+                let value = take_value(flag, inline, &mut args)?;
+                options.config.max_value = i64::try_from(parse_positive(flag, &value)?)
+                    .map_err(|_| format!("Niepoprawna wartość dla {flag}: {value}"))?;
+            }
+            _ if flag.starts_with("--") => return Err(format!("Nieznany argument: {arg}")),
+            _ if options.problem.is_none() => options.problem = Some(arg),
+            _ => return Err(format!("Nieznany argument: {arg}")),
+        }
+    }
+    Ok(options)
+}
+
+/// Uruchamia serię testów i wypisuje wynik. Zwraca `true`, gdy wszystkie przypadki są zgodne,
+/// i błąd, gdy rozwiązanie wzorcowe nie policzyło odpowiedzi.
+fn check<P: Problem>(
+    problem: &P,
+    solve: solvers::SolveFn,
+    config: &Config,
+) -> Result<bool, String> {
+    match stress::stress(problem, solve, config) {
+        Ok(count) => {
+            println!("OK: {count} przypadków zgodnych (ziarno {}).", config.seed);
+            Ok(true)
+        }
+        Err(StressError::Reference(failure)) => Err(format!(
+            "Rozwiązanie wzorcowe nie powiodło się w przypadku {} (ziarno przypadku {}): {}\nWejście:\n{}",
+            failure.iteration + 1,
+            failure.seed,
+            failure.message,
+            failure.input.trim_end()
+        )),
+        Err(StressError::Mismatch(failure)) => {
+            println!(
+                "Niezgodność w przypadku {} (ziarno przypadku {}).",
+                failure.iteration + 1,
+                failure.seed
+            );
+            println!(
+                "Przypadek zmniejszono w {} krokach. Minimalne wejście:",
+                failure.shrink_steps
+            );
+            print!("{}", failure.input);
+            println!("Oczekiwano:");
+            print!("{}", failure.expected);
+            println!("Otrzymano:");
+            println!("{}", failure.actual);
+            Ok(false)
+        }
+    }
+}
+
+fn run(options: &Options) -> Result<bool, String> {
+    let name = options
+        .problem
+        .as_deref()
+        .ok_or_else(|| "Nie podano zadania.".to_string())?;
+    let solver = solvers::find(name).ok_or_else(|| format!("Nieznane zadanie: {name}"))?;
+    let config = &options.config;
+    match solver.name {
+        "x_ex_2" => check(&RangeMin, solver.solve, config),
+        "x_ex_3" => check(&TreePath, solver.solve, config),
+        "x_ex_4" => check(&MaxFlow, solver.solve, config),
+        "x_ex_5" => check(&Convolution, solver.solve, config),
+        other => Err(format!("Brak generatora dla zadania {other}")),
+    }
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            process::exit(2);
+        }
+    };
+    if options.problem.is_none() {
+        for line in usage() {
+            println!("{line}");
+        }
+        return;
+    }
+
+    // Paniki rozwiązań (np. z `todo!()`) są przechwytywane i raportowane w wyniku;
+    // bez tego każdy zmniejszany przypadek wypisywałby komunikat domyślnego hooka.
+    panic::set_hook(Box::new(|_| {}));
+    match run(&options) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(message) => {
+            eprintln!("{message}");
+            process::exit(2);
+        }
+    }
+}
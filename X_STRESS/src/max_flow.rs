@@ -0,0 +1,195 @@
+//! `X_EX_4`: maksymalny przepływ i minimalny przekrój. Wzorzec to algorytm
+//! Edmondsa–Karpa (ścieżki powiększające wyznaczane przez BFS).
+use std::collections::VecDeque;
+
+use crate::rng::Rng;
+use crate::stress::{shrink_toward, Config, Problem, OVERFLOW};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowCase {
+    pub n: usize,
+    pub source: usize,
+    pub sink: usize,
+    /// Krawędzie skierowane `(u, v, pojemność)`, numerowane od 1 w kolejności listy.
+    pub edges: Vec<(usize, usize, i64)>,
+}
+
+/// Wynik wzorca: wartość przepływu, zbiór `S` osiągalny w sieci residualnej
+/// i identyfikatory krawędzi przekroju.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowAnswer {
+    pub max_flow: i64,
+    pub reachable: Vec<usize>,
+    pub cut_edges: Vec<usize>,
+}
+
+/// Algorytm Edmondsa–Karpa. Panikuje z [`OVERFLOW`], gdy przepływ nie mieści się w `i64`.
+pub fn edmonds_karp(case: &FlowCase) -> FlowAnswer {
+    // Krawędź `2i` to krawędź wejściowa `i`, a `2i + 1` — jej krawędź zwrotna.
+    let mut to = Vec::new();
+    let mut capacity = Vec::new();
+    let mut adjacency = vec![Vec::new(); case.n + 1];
+    for &(u, v, c) in &case.edges {
+        adjacency[u].push(to.len());
+        to.push(v);
+        capacity.push(c);
+        adjacency[v].push(to.len());
+        to.push(u);
+        capacity.push(0);
+    }
+
+    let residual_bfs = |capacity: &[i64]| {
+        let mut via = vec![None; case.n + 1];
+        let mut visited = vec![false; case.n + 1];
+        let mut queue = VecDeque::from([case.source]);
+        visited[case.source] = true;
+        while let Some(x) = queue.pop_front() {
+            for &edge in &adjacency[x] {
+                let y = to[edge];
+                if capacity[edge] > 0 && !visited[y] {
+                    visited[y] = true;
+                    via[y] = Some(edge);
+                    queue.push_back(y);
+                }
+            }
+        }
+        (visited, via)
+    };
+
+    let mut max_flow: i64 = 0;
+    loop {
+        let (visited, via) = residual_bfs(&capacity);
+        if !visited[case.sink] || case.source == case.sink {
+            break;
+        }
+        let mut bottleneck = i64::MAX;
+        let mut current = case.sink;
+        while let Some(edge) = via[current] {
+            bottleneck = bottleneck.min(capacity[edge]);
+            current = to[edge ^ 1];
+        }
+        let mut current = case.sink;
+        while let Some(edge) = via[current] {
+            capacity[edge] -= bottleneck;
+            capacity[edge ^ 1] += bottleneck;
+            current = to[edge ^ 1];
+        }
+        max_flow = max_flow.checked_add(bottleneck).expect(OVERFLOW);
+    }
+
+    let (visited, _) = residual_bfs(&capacity);
+    FlowAnswer {
+        max_flow,
+        reachable: (1..=case.n).filter(|&x| visited[x]).collect(),
+        cut_edges: case
+            .edges
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(u, v, _))| visited[u] && !visited[v])
+            .map(|(index, _)| index + 1)
+            .collect(),
+    }
+}
+
+pub struct MaxFlow;
+
+impl Problem for MaxFlow {
+    type Case = FlowCase;
+
+    fn generate(&self, rng: &mut Rng, config: &Config) -> FlowCase {
+        let n = rng.range_usize(2, config.max_size.max(2));
+        let source = rng.range_usize(1, n);
+        let mut sink = rng.range_usize(1, n);
+        while sink == source {
+            sink = rng.range_usize(1, n);
+        }
+        let m = rng.range_usize(1, config.max_size.max(1) * 2);
+        let edges = (0..m)
+            .map(|_| {
+                let u = rng.range_usize(1, n);
+                let mut v = rng.range_usize(1, n);
+                while v == u {
+                    v = rng.range_usize(1, n);
+                }
+                (u, v, rng.range_i64(1, config.max_value.max(1)))
+            })
+            .collect();
+        FlowCase {
+            n,
+            source,
+            sink,
+            edges,
+        }
+    }
+
+    fn input(&self, case: &FlowCase) -> String {
+        let mut text = format!(
+            "{} {} {} {}\n",
+            case.n,
+            case.edges.len(),
+            case.source,
+            case.sink
+        );
+        for (u, v, c) in &case.edges {
+            text.push_str(&format!("{u} {v} {c}\n"));
+        }
+        text
+    }
+
+    fn expected(&self, case: &FlowCase) -> String {
+        let answer = edmonds_karp(case);
+        let list = |items: &[usize]| {
+            std::iter::once(items.len())
+                .chain(items.iter().copied())
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        format!(
+            "{}\n{}\n{}\n",
+            answer.max_flow,
+            list(&answer.reachable),
+            list(&answer.cut_edges)
+        )
+    }
+
+    fn shrink(&self, case: &FlowCase) -> Vec<FlowCase> {
+        let mut candidates = Vec::new();
+
+        if case.edges.len() > 1 {
+            for index in 0..case.edges.len() {
+                let mut smaller = case.clone();
+                smaller.edges.remove(index);
+                candidates.push(smaller);
+            }
+        }
+
+        // Usunięcie wierzchołka innego niż źródło i ujście razem z jego krawędziami.
+        for vertex in (1..=case.n).filter(|&x| x != case.source && x != case.sink) {
+            let map = |x: usize| if x > vertex { x - 1 } else { x };
+            let edges: Vec<(usize, usize, i64)> = case
+                .edges
+                .iter()
+                .filter(|&&(u, v, _)| u != vertex && v != vertex)
+                .map(|&(u, v, c)| (map(u), map(v), c))
+                .collect();
+            if !edges.is_empty() {
+                candidates.push(FlowCase {
+                    n: case.n - 1,
+                    source: map(case.source),
+                    sink: map(case.sink),
+                    edges,
+                });
+            }
+        }
+
+        for (index, &(u, v, c)) in case.edges.iter().enumerate() {
+            for smaller in shrink_toward(c, 1) {
+                let mut candidate = case.clone();
+                candidate.edges[index] = (u, v, smaller);
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
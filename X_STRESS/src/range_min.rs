@@ -0,0 +1,146 @@
+//! `X_EX_2`: dodawanie na przedziale i minimum z przedziału. Wzorzec liczy każdą
+//! operację wprost na tablicy, w czasie `O(n)`.
+use crate::rng::Rng;
+use crate::stress::{shrink_toward, Config, Problem, OVERFLOW};
+
+/// Operacja na przedziale `[l, r)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add { l: usize, r: usize, x: i64 },
+    Min { l: usize, r: usize },
+}
+
+impl Operation {
+    fn range(self) -> (usize, usize) {
+        match self {
+            Operation::Add { l, r, .. } | Operation::Min { l, r } => (l, r),
+        }
+    }
+
+    fn with_range(self, l: usize, r: usize) -> Self {
+        match self {
+            Operation::Add { x, .. } => Operation::Add { l, r, x },
+            Operation::Min { .. } => Operation::Min { l, r },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMinCase {
+    pub values: Vec<i64>,
+    pub operations: Vec<Operation>,
+}
+
+pub struct RangeMin;
+
+impl Problem for RangeMin {
+    type Case = RangeMinCase;
+
+    fn generate(&self, rng: &mut Rng, config: &Config) -> RangeMinCase {
+        let n = rng.range_usize(1, config.max_size.max(1));
+        let q = rng.range_usize(1, config.max_size.max(1));
+        let values = (0..n)
+            .map(|_| rng.range_i64(-config.max_value, config.max_value))
+            .collect();
+        let operations = (0..q)
+            .map(|_| {
+                let l = rng.range_usize(0, n - 1);
+                let r = rng.range_usize(l + 1, n);
+                if rng.one_in(2) {
+                    let x = rng.range_i64(-config.max_value, config.max_value);
+                    Operation::Add { l, r, x }
+                } else {
+                    Operation::Min { l, r }
+                }
+            })
+            .collect();
+        RangeMinCase { values, operations }
+    }
+
+    fn input(&self, case: &RangeMinCase) -> String {
+        let values: Vec<String> = case.values.iter().map(i64::to_string).collect();
+        let mut text = format!(
+            "{} {}\n{}\n",
+            case.values.len(),
+            case.operations.len(),
+            values.join(" ")
+        );
+        for operation in &case.operations {
+            match *operation {
+                Operation::Add { l, r, x } => text.push_str(&format!("1 {l} {r} {x}\n")),
+                Operation::Min { l, r } => text.push_str(&format!("2 {l} {r}\n")),
+            }
+        }
+        text
+    }
+
+    fn expected(&self, case: &RangeMinCase) -> String {
+        let mut values = case.values.clone();
+        let mut output = String::new();
+        for operation in &case.operations {
+            match *operation {
+                Operation::Add { l, r, x } => values[l..r]
+                    .iter_mut()
+                    .for_each(|v| *v = v.checked_add(x).expect(OVERFLOW)),
+                Operation::Min { l, r } => {
+                    let min = values[l..r].iter().min().copied().unwrap_or_default();
+                    output.push_str(&format!("{min}\n"));
+                }
+            }
+        }
+        output
+    }
+
+    fn shrink(&self, case: &RangeMinCase) -> Vec<RangeMinCase> {
+        let mut candidates = Vec::new();
+
+        // Usunięcie operacji (co najmniej jedna musi zostać).
+        if case.operations.len() > 1 {
+            for index in 0..case.operations.len() {
+                let mut smaller = case.clone();
+                smaller.operations.remove(index);
+                candidates.push(smaller);
+            }
+        }
+
+        // Usunięcie elementu tablicy; przedziały za nim przesuwają się o jeden w lewo.
+        if case.values.len() > 1 {
+            for index in 0..case.values.len() {
+                let shift = |i: usize| if i > index { i - 1 } else { i };
+                let operations: Vec<Operation> = case
+                    .operations
+                    .iter()
+                    .filter_map(|operation| {
+                        let (l, r) = operation.range();
+                        let (l, r) = (shift(l), shift(r));
+                        (l < r).then(|| operation.with_range(l, r))
+                    })
+                    .collect();
+                if !operations.is_empty() {
+                    let mut values = case.values.clone();
+                    values.remove(index);
+                    candidates.push(RangeMinCase { values, operations });
+                }
+            }
+        }
+
+        // Zmniejszenie wartości i dodawanych liczb.
+        for (index, &value) in case.values.iter().enumerate() {
+            for smaller in shrink_toward(value, 0) {
+                let mut candidate = case.clone();
+                candidate.values[index] = smaller;
+                candidates.push(candidate);
+            }
+        }
+        for (index, operation) in case.operations.iter().enumerate() {
+            if let Operation::Add { l, r, x } = *operation {
+                for smaller in shrink_toward(x, 0) {
+                    let mut candidate = case.clone();
+                    candidate.operations[index] = Operation::Add { l, r, x: smaller };
+                    candidates.push(candidate);
+                }
+            }
+        }
+        candidates
+    }
+}
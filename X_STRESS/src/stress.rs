@@ -0,0 +1,226 @@
+//! Pętla testów losowych i zmniejszanie niezgodnych przypadków.
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::rng::Rng;
+
+/// Rozmiar i zakres losowanych przypadków.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Ziarno całej serii; przypadek `i` korzysta z ziarna [`case_seed`]`(seed, i)`.
+    pub seed: u64,
+    pub iterations: usize,
+    /// Największy rozmiar przypadku (długość tablicy, liczba wierzchołków, zapytań itp.).
+    pub max_size: usize,
+    /// Największa wartość bezwzględna losowanych liczb (wag, pojemności, elementów).
+    pub max_value: i64,
+    /// Limit prób przy zmniejszaniu przypadku.
+    pub max_shrink_steps: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            seed: 1,
+            iterations: 500,
+            max_size: 8,
+            max_value: 10,
+            max_shrink_steps: 10_000,
+        }
+    }
+}
+
+/// Zadanie: generator przypadków, rozwiązanie wzorcowe i sposób ich zmniejszania.
+pub trait Problem {
+    type Case: Clone + fmt::Debug;
+
+    /// Losuje przypadek mieszczący się w limitach `config`.
+    fn generate(&self, rng: &mut Rng, config: &Config) -> Self::Case;
+
+    /// Wejście programu w formacie z treści zadania.
+    fn input(&self, case: &Self::Case) -> String;
+
+    /// Oczekiwane wyjście policzone prostym (wolnym) algorytmem.
+    fn expected(&self, case: &Self::Case) -> String;
+
+    /// Poprawne przypadki o jeden krok mniejsze od `case`, od najbardziej obiecujących.
+    fn shrink(&self, case: &Self::Case) -> Vec<Self::Case>;
+}
+
+/// Ziarno przypadku `iteration` w serii o ziarnie `seed`.
+pub fn case_seed(seed: u64, iteration: usize) -> u64 {
+    Rng::new(seed ^ (iteration as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)).next_u64()
+}
+
+/// Wynik rozwiązania: wyjście albo komunikat błędu lub paniki.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Output(String),
+    Error(String),
+    Panic(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Output(output) => write!(f, "{}", output.trim_end()),
+            Answer::Error(message) => write!(f, "błąd: {message}"),
+            Answer::Panic(message) => write!(f, "panika: {message}"),
+        }
+    }
+}
+
+/// Pierwszy przypadek, dla którego rozwiązanie różni się od wzorcowego.
+#[derive(Debug, Clone)]
+pub struct Failure<C> {
+    pub iteration: usize,
+    /// Ziarno, z którego wylosowano przypadek (do odtworzenia bez całej serii).
+    pub seed: u64,
+    pub original: C,
+    /// Przypadek po zmniejszeniu.
+    pub case: C,
+    pub shrink_steps: usize,
+    pub input: String,
+    pub expected: String,
+    pub actual: Answer,
+}
+
+/// Przypadek, na którym rozwiązanie wzorcowe zakończyło się paniką (np. przepełnieniem).
+#[derive(Debug, Clone)]
+pub struct ReferenceFailure<C> {
+    pub iteration: usize,
+    pub seed: u64,
+    pub case: C,
+    pub input: String,
+    pub message: String,
+}
+
+/// Powód przerwania serii testów.
+#[derive(Debug, Clone)]
+pub enum StressError<C> {
+    /// Rozwiązanie różni się od wzorcowego; przypadek jest już zmniejszony.
+    Mismatch(Box<Failure<C>>),
+    /// Rozwiązanie wzorcowe nie policzyło odpowiedzi.
+    Reference(Box<ReferenceFailure<C>>),
+}
+
+/// Komunikat paniki wzorca, gdy wynik nie mieści się w `i64`.
+pub const OVERFLOW: &str = "przepełnienie i64 w rozwiązaniu wzorcowym";
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "nieznana przyczyna".to_string())
+}
+
+/// Uruchamia rozwiązanie, przechwytując panikę (np. z nierozwiązanego `todo!()`).
+pub fn run_solver<F>(solve: &F, input: &str) -> Answer
+where
+    F: Fn(&str) -> Result<String, String>,
+{
+    match panic::catch_unwind(AssertUnwindSafe(|| solve(input))) {
+        Ok(Ok(output)) => Answer::Output(output),
+        Ok(Err(message)) => Answer::Error(message),
+        Err(payload) => Answer::Panic(panic_message(payload)),
+    }
+}
+
+/// Liczy oczekiwane wyjście, przechwytując panikę wzorca.
+pub fn run_reference<P: Problem>(problem: &P, case: &P::Case) -> Result<String, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| problem.expected(case))).map_err(panic_message)
+}
+
+/// Sprawdza, czy odpowiedź zgadza się z oczekiwaną (porównanie tokenów).
+fn agrees(answer: &Answer, expected: &str) -> bool {
+    match answer {
+        Answer::Output(output) => output.split_whitespace().eq(expected.split_whitespace()),
+        _ => false,
+    }
+}
+
+/// Losuje `config.iterations` przypadków i porównuje rozwiązanie z wzorcowym.
+/// Przy pierwszej niezgodności zmniejsza przypadek i zwraca go jako błąd.
+///
+/// Paniki rozwiązania i wzorca są przechwytywane, ale domyślny hook nadal wypisuje
+/// ich komunikaty na stderr; program, który tego nie chce, sam ustawia `panic::set_hook`.
+pub fn stress<P, F>(problem: &P, solve: F, config: &Config) -> Result<usize, StressError<P::Case>>
+where
+    P: Problem,
+    F: Fn(&str) -> Result<String, String>,
+{
+    // `Ok(Some(odpowiedź))` oznacza niezgodność, `Err` — panikę wzorca.
+    let fails = |case: &P::Case| -> Result<Option<Answer>, String> {
+        let expected = run_reference(problem, case)?;
+        let answer = run_solver(&solve, &problem.input(case));
+        Ok((!agrees(&answer, &expected)).then_some(answer))
+    };
+
+    for iteration in 0..config.iterations {
+        let seed = case_seed(config.seed, iteration);
+        let original = problem.generate(&mut Rng::new(seed), config);
+        let mut actual = match fails(&original) {
+            Ok(None) => continue,
+            Ok(Some(answer)) => answer,
+            Err(message) => {
+                return Err(StressError::Reference(Box::new(ReferenceFailure {
+                    iteration,
+                    seed,
+                    input: problem.input(&original),
+                    case: original,
+                    message,
+                })));
+            }
+        };
+
+        // Zachłanne zmniejszanie: bierzemy pierwszego mniejszego kandydata, który nadal
+        // daje niezgodność, dopóki żaden kandydat jej nie daje.
+        let mut case = original.clone();
+        let mut steps = 0;
+        let mut attempts = 0;
+        'shrinking: loop {
+            for candidate in problem.shrink(&case) {
+                attempts += 1;
+                if attempts > config.max_shrink_steps {
+                    break 'shrinking;
+                }
+                // Kandydat, na którym wzorzec nie działa, nie nadaje się do zmniejszania.
+                if let Ok(Some(answer)) = fails(&candidate) {
+                    case = candidate;
+                    actual = answer;
+                    steps += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+
+        // Wzorzec zadziałał już na `case` podczas zmniejszania, więc nie spanikuje.
+        return Err(StressError::Mismatch(Box::new(Failure {
+            iteration,
+            seed,
+            original,
+            input: problem.input(&case),
+            expected: problem.expected(&case),
+            case,
+            shrink_steps: steps,
+            actual,
+        })));
+    }
+    Ok(config.iterations)
+}
+
+/// Kandydaci do zmniejszenia liczby: kolejno `target`, połowa odległości od `target`
+/// i wartość o 1 bliższa `target`. Każdy kandydat jest bliżej celu niż `value`.
+pub fn shrink_toward(value: i64, target: i64) -> Vec<i64> {
+    let distance = value - target;
+    let mut candidates = Vec::new();
+    for candidate in [target, target + distance / 2, value - distance.signum()] {
+        if candidate != value && !candidates.contains(&candidate) {
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
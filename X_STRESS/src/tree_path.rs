@@ -0,0 +1,206 @@
+//! `X_EX_3`: zapytania o ścieżki w ważonym drzewie. Wzorzec dla każdego zapytania
+//! wyznacza ścieżkę przeszukiwaniem wszerz (BFS) od `u` i przechodzi ją od `v` wstecz.
+use std::collections::VecDeque;
+
+use crate::rng::Rng;
+use crate::stress::{shrink_toward, Config, Problem, OVERFLOW};
+
+/// Zapytanie o ścieżkę `u → v` (wierzchołki numerowane od 1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Query {
+    Dist(usize, usize),
+    /// Wymaga `u != v` (ścieżka musi mieć krawędź).
+    Min(usize, usize),
+    /// `k`-ty wierzchołek ścieżki, licząc od 1.
+    Kth(usize, usize, usize),
+}
+
+impl Query {
+    fn endpoints(self) -> (usize, usize) {
+        match self {
+            Query::Dist(u, v) | Query::Min(u, v) | Query::Kth(u, v, _) => (u, v),
+        }
+    }
+
+    fn relabel(self, map: impl Fn(usize) -> usize) -> Self {
+        match self {
+            Query::Dist(u, v) => Query::Dist(map(u), map(v)),
+            Query::Min(u, v) => Query::Min(map(u), map(v)),
+            Query::Kth(u, v, k) => Query::Kth(map(u), map(v), k),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeCase {
+    pub n: usize,
+    /// Krawędzie `(u, v, waga)`.
+    pub edges: Vec<(usize, usize, i64)>,
+    pub queries: Vec<Query>,
+}
+
+/// Ścieżka `u → v`: kolejne wierzchołki i wagi krawędzi między nimi.
+pub fn path(case: &TreeCase, u: usize, v: usize) -> (Vec<usize>, Vec<i64>) {
+    let mut adjacency = vec![Vec::new(); case.n + 1];
+    for &(a, b, w) in &case.edges {
+        adjacency[a].push((b, w));
+        adjacency[b].push((a, w));
+    }
+
+    let mut parent = vec![None; case.n + 1];
+    let mut visited = vec![false; case.n + 1];
+    let mut queue = VecDeque::from([u]);
+    visited[u] = true;
+    while let Some(x) = queue.pop_front() {
+        for &(y, w) in &adjacency[x] {
+            if !visited[y] {
+                visited[y] = true;
+                parent[y] = Some((x, w));
+                queue.push_back(y);
+            }
+        }
+    }
+
+    let (mut vertices, mut weights) = (vec![v], Vec::new());
+    let mut current = v;
+    while let Some((previous, w)) = parent[current] {
+        vertices.push(previous);
+        weights.push(w);
+        current = previous;
+    }
+    vertices.reverse();
+    weights.reverse();
+    (vertices, weights)
+}
+
+pub struct TreePath;
+
+impl Problem for TreePath {
+    type Case = TreeCase;
+
+    fn generate(&self, rng: &mut Rng, config: &Config) -> TreeCase {
+        let n = rng.range_usize(1, config.max_size.max(1));
+        let mut labels: Vec<usize> = (1..=n).collect();
+        rng.shuffle(&mut labels);
+        let mut edges: Vec<(usize, usize, i64)> = (1..n)
+            .map(|i| {
+                let parent = labels[rng.range_usize(0, i - 1)];
+                let w = rng.range_i64(1, config.max_value.max(1));
+                if rng.one_in(2) {
+                    (labels[i], parent, w)
+                } else {
+                    (parent, labels[i], w)
+                }
+            })
+            .collect();
+        rng.shuffle(&mut edges);
+
+        let mut case = TreeCase {
+            n,
+            edges,
+            queries: Vec::new(),
+        };
+        let q = rng.range_usize(1, config.max_size.max(1));
+        for _ in 0..q {
+            let u = rng.range_usize(1, n);
+            let mut v = rng.range_usize(1, n);
+            let query = match rng.below(3) {
+                0 if n > 1 => {
+                    while v == u {
+                        v = rng.range_usize(1, n);
+                    }
+                    Query::Min(u, v)
+                }
+                1 => {
+                    let (vertices, _) = path(&case, u, v);
+                    Query::Kth(u, v, rng.range_usize(1, vertices.len()))
+                }
+                _ => Query::Dist(u, v),
+            };
+            case.queries.push(query);
+        }
+        case
+    }
+
+    fn input(&self, case: &TreeCase) -> String {
+        let mut text = format!("{} {}\n", case.n, case.queries.len());
+        for (u, v, w) in &case.edges {
+            text.push_str(&format!("{u} {v} {w}\n"));
+        }
+        for query in &case.queries {
+            match *query {
+                Query::Dist(u, v) => text.push_str(&format!("DIST {u} {v}\n")),
+                Query::Min(u, v) => text.push_str(&format!("MIN {u} {v}\n")),
+                Query::Kth(u, v, k) => text.push_str(&format!("KTH {u} {v} {k}\n")),
+            }
+        }
+        text
+    }
+
+    fn expected(&self, case: &TreeCase) -> String {
+        let mut output = String::new();
+        for query in &case.queries {
+            let (u, v) = query.endpoints();
+            let (vertices, weights) = path(case, u, v);
+            let answer = match *query {
+                Query::Dist(..) => weights
+                    .iter()
+                    .try_fold(0i64, |sum, &w| sum.checked_add(w))
+                    .expect(OVERFLOW),
+                Query::Min(..) => weights.iter().min().copied().unwrap_or_default(),
+                Query::Kth(_, _, k) => vertices[k - 1] as i64,
+            };
+            output.push_str(&format!("{answer}\n"));
+        }
+        output
+    }
+
+    fn shrink(&self, case: &TreeCase) -> Vec<TreeCase> {
+        let mut candidates = Vec::new();
+
+        if case.queries.len() > 1 {
+            for index in 0..case.queries.len() {
+                let mut smaller = case.clone();
+                smaller.queries.remove(index);
+                candidates.push(smaller);
+            }
+        }
+
+        // Usunięcie liścia, który nie jest końcem żadnej ścieżki z zapytań (liść nie leży
+        // wewnątrz żadnej ścieżki, więc odpowiedzi się nie zmieniają poza numeracją).
+        let mut degree = vec![0; case.n + 1];
+        for &(u, v, _) in &case.edges {
+            degree[u] += 1;
+            degree[v] += 1;
+        }
+        for leaf in (1..=case.n).filter(|&x| degree[x] == 1) {
+            let used = case.queries.iter().any(|query| {
+                let (u, v) = query.endpoints();
+                u == leaf || v == leaf
+            });
+            if used {
+                continue;
+            }
+            let map = |x: usize| if x > leaf { x - 1 } else { x };
+            candidates.push(TreeCase {
+                n: case.n - 1,
+                edges: case
+                    .edges
+                    .iter()
+                    .filter(|&&(u, v, _)| u != leaf && v != leaf)
+                    .map(|&(u, v, w)| (map(u), map(v), w))
+                    .collect(),
+                queries: case.queries.iter().map(|q| q.relabel(map)).collect(),
+            });
+        }
+
+        for (index, &(u, v, w)) in case.edges.iter().enumerate() {
+            for smaller in shrink_toward(w, 1) {
+                let mut candidate = case.clone();
+                candidate.edges[index] = (u, v, smaller);
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
@@ -0,0 +1,130 @@
+use x_stress::convolution::{Convolution, ConvolutionCase};
+use x_stress::max_flow::{FlowCase, MaxFlow};
+use x_stress::range_min::{Operation, RangeMin, RangeMinCase};
+use x_stress::rng::Rng;
+use x_stress::stress::{self, case_seed, shrink_toward, Config, Problem, StressError};
+use x_stress::tree_path::{path, Query, TreeCase, TreePath};
+
+#[test]
+fn shrink_toward_moves_closer_to_target() {
+    assert_eq!(shrink_toward(10, 0), vec![0, 5, 9]);
+    assert_eq!(shrink_toward(-7, 0), vec![0, -3, -6]);
+    assert_eq!(shrink_toward(2, 0), vec![0, 1]);
+    assert_eq!(shrink_toward(5, 1), vec![1, 3, 4]);
+    assert_eq!(shrink_toward(1, 1), Vec::<i64>::new());
+    assert_eq!(shrink_toward(i64::MIN, 0), vec![0, i64::MIN / 2, i64::MIN + 1]);
+}
+
+#[test]
+fn rng_is_deterministic() {
+    let sequence = |seed| {
+        let mut rng = Rng::new(seed);
+        (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+    };
+    assert_eq!(sequence(7), sequence(7));
+    assert_ne!(sequence(7), sequence(8));
+    assert_eq!(case_seed(1, 3), case_seed(1, 3));
+    assert_ne!(case_seed(1, 3), case_seed(1, 4));
+
+    let mut rng = Rng::new(42);
+    for _ in 0..1000 {
+        assert!((3..=5).contains(&rng.range_usize(3, 5)));
+        assert!((-2..=2).contains(&rng.range_i64(-2, 2)));
+    }
+    let mut items: Vec<u32> = (0..10).collect();
+    rng.shuffle(&mut items);
+    items.sort_unstable();
+    assert_eq!(items, (0..10).collect::<Vec<_>>());
+}
+
+fn valid_range_min(case: &RangeMinCase) -> bool {
+    let n = case.values.len();
+    n > 0
+        && !case.operations.is_empty()
+        && case.operations.iter().all(|operation| match *operation {
+            Operation::Add { l, r, .. } | Operation::Min { l, r } => l < r && r <= n,
+        })
+}
+
+fn valid_convolution(case: &ConvolutionCase) -> bool {
+    let len = case.a.len() + case.b.len();
+    !case.a.is_empty()
+        && !case.b.is_empty()
+        && !case.queries.is_empty()
+        && case.queries.iter().all(|&t| t < len - 1)
+}
+
+fn valid_flow(case: &FlowCase) -> bool {
+    let vertex = |x: usize| (1..=case.n).contains(&x);
+    vertex(case.source)
+        && vertex(case.sink)
+        && case.source != case.sink
+        && !case.edges.is_empty()
+        && case.edges.iter().all(|&(u, v, c)| vertex(u) && vertex(v) && u != v && c >= 1)
+}
+
+fn valid_tree(case: &TreeCase) -> bool {
+    let vertex = |x: usize| (1..=case.n).contains(&x);
+    let connected = (1..=case.n).all(|v| path(case, 1, v).0.first() == Some(&1));
+    case.edges.len() + 1 == case.n
+        && connected
+        && case.edges.iter().all(|&(u, v, w)| vertex(u) && vertex(v) && w >= 1)
+        && !case.queries.is_empty()
+        && case.queries.iter().all(|query| match *query {
+            Query::Dist(u, v) => vertex(u) && vertex(v),
+            Query::Min(u, v) => vertex(u) && vertex(v) && u != v,
+            Query::Kth(u, v, k) => vertex(u) && vertex(v) && (1..=path(case, u, v).0.len()).contains(&k),
+        })
+}
+
+/// Sprawdza, że wylosowane przypadki i wszyscy ich kandydaci do zmniejszenia są poprawni.
+fn check_shrinker<P: Problem>(problem: &P, valid: impl Fn(&P::Case) -> bool) {
+    let config = Config::default();
+    for iteration in 0..50 {
+        let case = problem.generate(&mut Rng::new(case_seed(3, iteration)), &config);
+        assert!(valid(&case), "{case:?}");
+        for candidate in problem.shrink(&case) {
+            assert!(valid(&candidate), "{case:?} -> {candidate:?}");
+            assert!(stress::run_reference(problem, &candidate).is_ok());
+        }
+    }
+}
+
+#[test]
+fn shrinking_keeps_cases_valid() {
+    check_shrinker(&RangeMin, valid_range_min);
+    check_shrinker(&Convolution, valid_convolution);
+    check_shrinker(&MaxFlow, valid_flow);
+    check_shrinker(&TreePath, valid_tree);
+}
+
+/// Błędne rozwiązanie `X_EX_2`: pomija operacje dodawania.
+fn ignore_additions(input: &str) -> Result<String, String> {
+    let mut lines = input.lines();
+    let values: Vec<i64> = lines.nth(1).unwrap().split_whitespace().map(|x| x.parse().unwrap()).collect();
+    let mut output = String::new();
+    for line in lines {
+        let tokens: Vec<usize> = line.split_whitespace().take(3).map(|x| x.parse().unwrap()).collect();
+        if tokens[0] == 2 {
+            output.push_str(&format!("{}\n", values[tokens[1]..tokens[2]].iter().min().unwrap()));
+        }
+    }
+    Ok(output)
+}
+
+#[test]
+fn wrong_solver_shrinks_to_a_minimal_case() {
+    let failure = match stress::stress(&RangeMin, ignore_additions, &Config::default()) {
+        Err(StressError::Mismatch(failure)) => failure,
+        other => panic!("oczekiwano niezgodności, otrzymano {other:?}"),
+    };
+    assert!(failure.shrink_steps > 0);
+    assert_eq!(failure.case.values, vec![0]);
+    let [Operation::Add { l: 0, r: 1, x }, Operation::Min { l: 0, r: 1 }] = failure.case.operations[..] else {
+        panic!("nieoczekiwany przypadek {:?}", failure.case);
+    };
+    assert_eq!(x.abs(), 1);
+    assert_eq!(failure.input, format!("1 2\n0\n1 0 1 {x}\n2 0 1\n"));
+    assert_eq!(failure.expected, format!("{x}\n"));
+    assert_eq!(failure.actual.to_string(), "0");
+}
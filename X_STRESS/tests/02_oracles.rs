@@ -0,0 +1,84 @@
+use x_stress::convolution::{Convolution, ConvolutionCase};
+use x_stress::max_flow::{MaxFlow, FlowCase};
+use x_stress::range_min::{Operation, RangeMin, RangeMinCase};
+use x_stress::stress::{self, Config, Problem, StressError, OVERFLOW};
+use x_stress::tree_path::{Query, TreeCase, TreePath};
+
+#[test]
+fn range_min_readme_example() {
+    let case = RangeMinCase {
+        values: vec![3, -2, 7, 4, 1],
+        operations: vec![
+            Operation::Min { l: 1, r: 4 },
+            Operation::Add { l: 0, r: 3, x: -5 },
+            Operation::Min { l: 0, r: 5 },
+            Operation::Add { l: 2, r: 5, x: 2 },
+            Operation::Min { l: 3, r: 5 },
+        ],
+    };
+    assert_eq!(RangeMin.input(&case), "5 5\n3 -2 7 4 1\n2 1 4\n1 0 3 -5\n2 0 5\n1 2 5 2\n2 3 5\n");
+    // README `X_EX_2` podaje w drugiej linii -2, ale po dodaniu -5 do [0, 3) a[1] = -7.
+    assert_eq!(RangeMin.expected(&case), "-2\n-7\n3\n");
+}
+
+#[test]
+fn tree_path_readme_example() {
+    let case = TreeCase {
+        n: 6,
+        edges: vec![(1, 2, 4), (2, 3, 2), (2, 4, 6), (1, 5, 3), (5, 6, 5)],
+        queries: vec![
+            Query::Dist(3, 6),
+            Query::Min(3, 6),
+            Query::Kth(3, 6, 4),
+            Query::Dist(4, 3),
+            Query::Kth(5, 4, 2),
+        ],
+    };
+    assert_eq!(
+        TreePath.input(&case),
+        "6 5\n1 2 4\n2 3 2\n2 4 6\n1 5 3\n5 6 5\nDIST 3 6\nMIN 3 6\nKTH 3 6 4\nDIST 4 3\nKTH 5 4 2\n"
+    );
+    assert_eq!(TreePath.expected(&case), "14\n2\n5\n8\n1\n");
+}
+
+#[test]
+fn max_flow_readme_example() {
+    let case = FlowCase {
+        n: 4,
+        source: 1,
+        sink: 4,
+        edges: vec![(1, 2, 5), (1, 3, 4), (2, 3, 2), (2, 4, 3), (3, 4, 4)],
+    };
+    assert_eq!(MaxFlow.input(&case), "4 5 1 4\n1 2 5\n1 3 4\n2 3 2\n2 4 3\n3 4 4\n");
+    // README `X_EX_4` podaje S = {1, 2}, ale krawędź 2 → 3 ma wolną pojemność, więc 3 też jest
+    // osiągalny z 1; przekrój tworzą krawędzie 4 i 5 (również o wartości 7).
+    assert_eq!(MaxFlow.expected(&case), "7\n3 1 2 3\n2 4 5\n");
+}
+
+#[test]
+fn convolution_readme_example() {
+    let case = ConvolutionCase { a: vec![1, 0, -1, 2], b: vec![2, 3, 4], queries: vec![0, 1, 4, 5] };
+    assert_eq!(Convolution.input(&case), "4 3 4\n1 0 -1 2\n2 3 4\n0\n1\n4\n5\n");
+    // README `X_EX_5` podaje splot [2, 3, 2, 7, 5, 8]; z definicji c[3] = 0·4 - 1·3 + 2·2 = 1
+    // i c[4] = -1·4 + 2·3 = 2.
+    assert_eq!(Convolution.expected(&case), "2\n3\n2\n8\n");
+}
+
+#[test]
+fn overflowing_reference_is_reported_instead_of_crashing() {
+    let case = ConvolutionCase { a: vec![i64::MAX], b: vec![2], queries: vec![0] };
+    assert_eq!(stress::run_reference(&Convolution, &case), Err(OVERFLOW.to_string()));
+
+    let config = Config { max_value: 10_000_000_000, ..Config::default() };
+    let solve = |_: &str| -> Result<String, String> { Ok(String::new()) };
+    match stress::stress(&Convolution, solve, &config) {
+        Err(StressError::Reference(failure)) => {
+            assert_eq!(failure.message, OVERFLOW);
+            assert_eq!(failure.input, Convolution.input(&failure.case));
+        }
+        other => panic!("oczekiwano błędu wzorca, otrzymano {other:?}"),
+    }
+
+    let config = Config { max_value: i64::MAX, ..Config::default() };
+    assert!(matches!(stress::stress(&RangeMin, solve, &config), Err(StressError::Reference(_))));
+}